@@ -0,0 +1,362 @@
+use std::path::PathBuf;
+
+use crate::test::{Challenge, MAX_SECONDS, MAX_WORDS};
+use crate::ui::Theme;
+
+/// Options for a run, from the command line
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Args {
+    /// Time test length in seconds
+    pub time: Option<u32>,
+    /// Words test length
+    pub words: Option<u32>,
+    /// Word list (name or file) to draw random words from
+    pub wordlist: Option<String>,
+    /// File whose text is typed as is
+    pub text: Option<PathBuf>,
+    /// Type a random quote instead of random words
+    pub quote: bool,
+    /// Take today's daily challenge
+    pub daily: bool,
+    /// Challenge code to recreate a shared test
+    pub challenge: Option<Challenge>,
+    /// Seed for the random word order
+    pub seed: Option<u64>,
+    /// Color theme
+    pub theme: Option<Theme>,
+    /// Directory for profile.json and config.json
+    pub profile_dir: Option<PathBuf>,
+    /// Quit after the test and print a summary line
+    pub print_result: bool,
+    /// Quit after the test and print the result as JSON
+    pub json: bool,
+}
+
+impl Args {
+    /// Check if the result is printed after the test
+    pub fn prints_result(&self) -> bool {
+        self.print_result || self.json
+    }
+}
+
+/// Options for the `stats` and `history` subcommands
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReportArgs {
+    /// Print JSON instead of a table
+    pub json: bool,
+    /// Most results to list (history only)
+    pub limit: Option<usize>,
+    /// List the daily challenge results instead of all results (history only)
+    pub daily: bool,
+    /// Directory for profile.json
+    pub profile_dir: Option<PathBuf>,
+}
+
+/// Results listed by `history` when no --limit is given
+pub const DEFAULT_HISTORY_LIMIT: usize = 20;
+
+/// What the command line asks for
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Start the typing test
+    Run(Args),
+    /// Print the best scores and averages
+    Stats(ReportArgs),
+    /// Print the recent results
+    History(ReportArgs),
+    /// Print the usage
+    Help,
+    /// Print the usage of `stats`
+    StatsHelp,
+    /// Print the usage of `history`
+    HistoryHelp,
+    /// Print the version
+    Version,
+}
+
+/// Get the usage text printed by --help
+pub fn usage() -> String {
+    format!(
+        "\
+Terminal typing speed test
+
+Usage: termotype [OPTIONS]
+       termotype stats [--json] [--profile-dir <DIR>]
+       termotype history [--json] [--limit <N>] [--daily] [--profile-dir <DIR>]
+
+Commands:
+  stats                Print best scores and averages for each mode
+  history              Print the most recent results (default {history_limit})
+
+Options:
+  --time <SECONDS>     Time test of the given length (1-{max_seconds})
+  --words <COUNT>      Words test of the given length (1-{max_words})
+  --wordlist <NAME>    Word list: english, english_1k, english_10k, german, polish, one of yours,
+                       or the path of a file (JSON array of strings)
+  --text <PATH>        Type the text of a file, word by word
+  --quote              Type a random quote
+  --daily              Take today's daily challenge (the same test for everyone)
+                       (with history: list the results of past daily challenges)
+  --challenge <CODE>   Take the test of a challenge code shown with a result
+  --seed <N>           Seed for the random word order, to repeat a test exactly
+  --theme <NAME>       Color theme: {themes}
+  --profile-dir <DIR>  Directory for profile.json and config.json
+  --print-result       Quit when the test is finished and print a summary line
+  --json               Quit when the test is finished and print the result as JSON
+                       (with stats and history: print them as JSON)
+  -h, --help           Print this help
+  -V, --version        Print the version
+  --limit <N>          Number of results printed by history
+
+Exit status with --print-result or --json:
+  0    the test was finished
+  1    an error occurred
+  2    invalid command line
+  3    the test was aborted (quit before the end)
+  129  the terminal was hung up (SIGHUP)",
+        max_seconds = MAX_SECONDS,
+        max_words = MAX_WORDS,
+        history_limit = DEFAULT_HISTORY_LIMIT,
+        themes = Theme::all().iter().map(|theme| theme.config_name()).collect::<Vec<_>>().join(", "),
+    )
+}
+
+/// Get the usage text printed by `stats --help`
+pub fn stats_usage() -> String {
+    "\
+Print best scores and averages for each mode
+
+Usage: termotype stats [OPTIONS]
+
+Options:
+  --json               Print them as JSON
+  --profile-dir <DIR>  Directory of profile.json
+  -h, --help           Print this help"
+        .to_string()
+}
+
+/// Get the usage text printed by `history --help`
+pub fn history_usage() -> String {
+    format!(
+        "\
+Print the most recent results, newest first
+
+Usage: termotype history [OPTIONS]
+
+Options:
+  --json               Print them as JSON
+  --limit <N>          Number of results to print (default {history_limit})
+  --daily              List the results of past daily challenges instead
+  --profile-dir <DIR>  Directory of profile.json
+  -h, --help           Print this help",
+        history_limit = DEFAULT_HISTORY_LIMIT,
+    )
+}
+
+/// Parse the command line arguments (without the program name)
+/// Values can follow their flag as the next argument or after '=' (`--time=60`).
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("stats") => {
+            args.next();
+            parse_report(args, false).map(|report| report.map_or(Command::StatsHelp, Command::Stats))
+        }
+        Some("history") => {
+            args.next();
+            parse_report(args, true).map(|report| report.map_or(Command::HistoryHelp, Command::History))
+        }
+        _ => parse_run(args),
+    }
+}
+
+/// Split `--flag=value` into the flag and its value
+fn split_flag(arg: &str) -> (String, Option<String>) {
+    match arg.split_once('=') {
+        Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+        _ => (arg.to_string(), None),
+    }
+}
+
+/// Parse the options of a `stats` or `history` subcommand
+/// Returns None when help was asked for.
+fn parse_report<I: Iterator<Item = String>>(mut args: I, history: bool) -> Result<Option<ReportArgs>, String> {
+    let mut parsed = ReportArgs::default();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = split_flag(&arg);
+        let mut value = || inline_value.clone().or_else(|| args.next()).ok_or_else(|| format!("{} needs a value", flag));
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--json" => parsed.json = true,
+            "--limit" if history => {
+                let limit = value()?;
+                parsed.limit = Some(limit.parse().map_err(|_| format!("invalid limit '{}'", limit))?);
+            }
+            "--daily" if history => parsed.daily = true,
+            "--profile-dir" => parsed.profile_dir = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+    }
+
+    Ok(Some(parsed))
+}
+
+/// Parse the options for a typing test run
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut parsed = Args::default();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = split_flag(&arg);
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{} needs a value", flag))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "--time" => parsed.time = Some(parse_length(&flag, &value()?, MAX_SECONDS)?),
+            "--words" => parsed.words = Some(parse_length(&flag, &value()?, MAX_WORDS)?),
+            "--wordlist" => parsed.wordlist = Some(value()?),
+            "--text" => parsed.text = Some(PathBuf::from(value()?)),
+            "--quote" => parsed.quote = true,
+            "--daily" => parsed.daily = true,
+            "--challenge" => {
+                let code = value()?;
+                parsed.challenge =
+                    Some(Challenge::parse(&code).map_err(|err| format!("invalid challenge code '{}': {}", code, err))?);
+            }
+            "--seed" => {
+                let seed = value()?;
+                parsed.seed = Some(seed.parse().map_err(|_| format!("invalid seed '{}'", seed))?);
+            }
+            "--theme" => {
+                let name = value()?;
+                parsed.theme = Some(Theme::from_config_name(&name).ok_or_else(|| format!("unknown theme '{}'", name))?);
+            }
+            "--profile-dir" => parsed.profile_dir = Some(PathBuf::from(value()?)),
+            "--print-result" => parsed.print_result = true,
+            "--json" => parsed.json = true,
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+    }
+
+    if parsed.time.is_some() && parsed.words.is_some() {
+        return Err("--time and --words can't be used together".to_string());
+    }
+    let sources =
+        [parsed.wordlist.is_some(), parsed.text.is_some(), parsed.quote, parsed.daily, parsed.challenge.is_some()];
+    if sources.iter().filter(|&&set| set).count() > 1 {
+        return Err("only one of --wordlist, --text, --quote, --daily and --challenge can be used".to_string());
+    }
+    if (parsed.daily || parsed.challenge.is_some())
+        && (parsed.time.is_some() || parsed.words.is_some() || parsed.seed.is_some())
+    {
+        let flag = if parsed.daily { "--daily" } else { "--challenge" };
+        return Err(format!("{} sets the mode and seed itself", flag));
+    }
+
+    Ok(Command::Run(parsed))
+}
+
+/// Parse a test length, keeping it within 1..=max
+fn parse_length(flag: &str, value: &str, max: u32) -> Result<u32, String> {
+    value
+        .parse::<u32>()
+        .ok()
+        .filter(|n| (1..=max).contains(n))
+        .ok_or_else(|| format!("{} must be a number between 1 and {}", flag, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::TestMode;
+
+    fn args(line: &str) -> Result<Command, String> {
+        parse(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run_options() {
+        let Command::Run(parsed) = args("--time 60 --seed=42 --theme mono --profile-dir /tmp/tt").unwrap() else {
+            panic!("expected a run");
+        };
+        assert_eq!(parsed.time, Some(60));
+        assert_eq!(parsed.seed, Some(42));
+        assert_eq!(parsed.theme, Some(Theme::Mono));
+        assert_eq!(parsed.profile_dir, Some(PathBuf::from("/tmp/tt")));
+        assert_eq!(args("").unwrap(), Command::Run(Args::default()));
+
+        let Command::Run(parsed) = args("--json").unwrap() else {
+            panic!("expected a run");
+        };
+        assert!(parsed.prints_result());
+    }
+
+    #[test]
+    fn test_challenge_code() {
+        let Command::Run(parsed) = args("--challenge=tt1:t15:0000abcd:z:words.json").unwrap() else {
+            panic!("expected a run");
+        };
+        let challenge = parsed.challenge.unwrap();
+        assert_eq!(challenge.mode, TestMode::Time(15));
+        assert_eq!(challenge.seed, 35);
+    }
+
+    #[test]
+    fn test_help_and_version() {
+        assert_eq!(args("--words 10 --help").unwrap(), Command::Help);
+        assert_eq!(args("-V").unwrap(), Command::Version);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert!(args("--time").is_err());
+        assert!(args("--time 0").is_err());
+        assert!(args("--words lots").is_err());
+        assert!(args("--theme neon").is_err());
+        assert!(args("--time 30 --words 30").is_err());
+        assert!(args("--quote --text notes.txt").is_err());
+        assert!(args("--daily --time 60").is_err());
+        assert!(args("--challenge tt1:w50:00000000:1:words.json --seed 5").is_err());
+        assert!(args("--challenge tt9:w50").is_err());
+        assert!(args("--fast").is_err());
+        assert!(args("stats --limit 5").is_err());
+        assert!(args("history --limit many").is_err());
+        assert!(args("stats --daily").is_err());
+    }
+
+    #[test]
+    fn test_report_subcommands() {
+        assert_eq!(args("stats").unwrap(), Command::Stats(ReportArgs::default()));
+        assert_eq!(
+            args("history --json --limit=5 --profile-dir /tmp/tt").unwrap(),
+            Command::History(ReportArgs {
+                json: true,
+                limit: Some(5),
+                daily: false,
+                profile_dir: Some(PathBuf::from("/tmp/tt")),
+            })
+        );
+        assert!(matches!(args("history --daily").unwrap(), Command::History(ReportArgs { daily: true, .. })));
+        assert_eq!(args("history --help").unwrap(), Command::HistoryHelp);
+        assert_eq!(args("stats --json -h").unwrap(), Command::StatsHelp);
+    }
+
+    #[test]
+    fn test_subcommand_usage_lists_their_options() {
+        for flag in ["--json", "--profile-dir"] {
+            assert!(stats_usage().contains(flag));
+            assert!(history_usage().contains(flag));
+        }
+        assert!(history_usage().contains("--limit"));
+        assert!(history_usage().contains("--daily"));
+        assert!(!stats_usage().contains("--limit"));
+    }
+}
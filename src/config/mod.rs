@@ -0,0 +1,5 @@
+pub mod models;
+pub mod storage;
+
+pub use models::Config;
+pub use storage::{save_config, load_config};
@@ -0,0 +1,218 @@
+use anyhow::{bail, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::i18n::Language;
+use crate::settings::RestartMode;
+use crate::test::{TestMode, DEFAULT_WORD_LIST, MAX_SECONDS, MAX_WORDS};
+use crate::ui::{CaretStyle, ErrorStyle, Theme};
+
+/// Word list file that was the default before the lists were bundled (now the default list)
+const LEGACY_WORD_LIST: &str = "words.json";
+
+/// User settings persisted between runs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    /// Last used test mode
+    pub test_mode: TestMode,
+    /// Name of the word list, or path of a word list file
+    pub word_list: String,
+    /// What the restart action does
+    pub restart_mode: RestartMode,
+    /// How typing errors are marked
+    pub error_style: ErrorStyle,
+    /// Show the typed character in place of the expected one on mistakes
+    pub show_typed_chars: bool,
+    /// Caret appearance
+    pub caret_style: CaretStyle,
+    /// Whether the caret blinks
+    pub caret_blink: bool,
+    /// Hide everything but the words while a test is running
+    pub focus_mode: bool,
+    /// Show the time in focus mode
+    pub focus_timer: bool,
+    /// Color theme
+    pub theme: Theme,
+    /// Interface language (None follows the environment)
+    pub language: Option<Language>,
+    /// Keys for named actions, replacing the defaults of the listed actions
+    /// (e.g. `"restart": ["esc"]`)
+    pub keybindings: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            test_mode: TestMode::default(),
+            word_list: DEFAULT_WORD_LIST.to_string(),
+            restart_mode: RestartMode::default(),
+            error_style: ErrorStyle::default(),
+            show_typed_chars: false,
+            caret_style: CaretStyle::default(),
+            caret_blink: true,
+            focus_mode: false,
+            focus_timer: true,
+            theme: Theme::default(),
+            language: None,
+            keybindings: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Parse a config from JSON, field by field
+    /// Unknown keys are ignored and missing or invalid ones keep their defaults,
+    /// so one bad value doesn't discard the rest of the file.
+    /// Returns the config and a description of every problem found.
+    pub fn from_json(json: &str) -> (Self, Vec<String>) {
+        let mut config = Config::default();
+        let mut errors = Vec::new();
+
+        let object = match serde_json::from_str::<Value>(json) {
+            Ok(Value::Object(object)) => object,
+            Ok(_) => {
+                errors.push("expected a JSON object".to_string());
+                return (config, errors);
+            }
+            Err(err) => {
+                errors.push(format!("invalid JSON: {}", err));
+                return (config, errors);
+            }
+        };
+
+        read_field(&object, "test_mode", &mut config.test_mode, &mut errors);
+        read_field(&object, "word_list", &mut config.word_list, &mut errors);
+        read_field(&object, "restart_mode", &mut config.restart_mode, &mut errors);
+        read_field(&object, "error_style", &mut config.error_style, &mut errors);
+        read_field(&object, "show_typed_chars", &mut config.show_typed_chars, &mut errors);
+        read_field(&object, "caret_style", &mut config.caret_style, &mut errors);
+        read_field(&object, "caret_blink", &mut config.caret_blink, &mut errors);
+        read_field(&object, "focus_mode", &mut config.focus_mode, &mut errors);
+        read_field(&object, "focus_timer", &mut config.focus_timer, &mut errors);
+        read_field(&object, "theme", &mut config.theme, &mut errors);
+        read_field(&object, "language", &mut config.language, &mut errors);
+        read_field(&object, "keybindings", &mut config.keybindings, &mut errors);
+
+        if config.word_list == LEGACY_WORD_LIST {
+            config.word_list = DEFAULT_WORD_LIST.to_string();
+        }
+
+        let (length, max) = match config.test_mode {
+            TestMode::Time(n) => (n, MAX_SECONDS),
+            TestMode::Words(n) => (n, MAX_WORDS),
+        };
+        if !(1..=max).contains(&length) {
+            errors.push(format!("test_mode: length must be between 1 and {}", max));
+            config.test_mode = TestMode::default();
+        }
+
+        (config, errors)
+    }
+
+    /// Write the config into the JSON of an existing config file (None if there is no file yet)
+    /// Keys the config doesn't know are kept, so hand-written notes or settings of a newer version survive.
+    /// Fails if `existing` isn't a JSON object, so a file the user still has to fix isn't replaced.
+    pub fn merge_into_json(&self, existing: Option<&str>) -> Result<String> {
+        let mut object = match existing.map(serde_json::from_str::<Value>) {
+            None => Map::new(),
+            Some(Ok(Value::Object(object))) => object,
+            Some(_) => bail!("not a valid JSON object, so it is left unchanged"),
+        };
+
+        let Value::Object(fields) = serde_json::to_value(self).context("Failed to serialize config")? else {
+            bail!("config doesn't serialize to an object");
+        };
+        object.extend(fields);
+
+        serde_json::to_string_pretty(&object).context("Failed to serialize config")
+    }
+}
+
+/// Read a single field into `target`, leaving it unchanged if missing or invalid
+fn read_field<T: DeserializeOwned>(object: &Map<String, Value>, key: &str, target: &mut T, errors: &mut Vec<String>) {
+    let Some(value) = object.get(key) else {
+        return;
+    };
+
+    match serde_json::from_value(value.clone()) {
+        Ok(parsed) => *target = parsed,
+        Err(err) => errors.push(format!("{}: {}", key, err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let config = Config {
+            test_mode: TestMode::Words(50),
+            caret_style: CaretStyle::Bar,
+            restart_mode: RestartMode::SameWords,
+            language: Some(Language::Polish),
+            ..Config::default()
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        let (parsed, errors) = Config::from_json(&json);
+        assert!(errors.is_empty());
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn test_missing_and_unknown_keys() {
+        let (config, errors) = Config::from_json(r#"{"caret_blink": false, "future_option": 1}"#);
+        assert!(errors.is_empty());
+        assert!(!config.caret_blink);
+        assert_eq!(config.test_mode, TestMode::default());
+    }
+
+    #[test]
+    fn test_legacy_word_list_is_the_default_list() {
+        let (config, _) = Config::from_json(r#"{"word_list": "words.json"}"#);
+        assert_eq!(config.word_list, DEFAULT_WORD_LIST);
+        let (config, _) = Config::from_json(r#"{"word_list": "lists/words.json"}"#);
+        assert_eq!(config.word_list, "lists/words.json");
+    }
+
+    #[test]
+    fn test_invalid_values_keep_defaults() {
+        let (config, errors) =
+            Config::from_json(r#"{"caret_style": "blinky", "test_mode": {"time": 0}, "show_typed_chars": true}"#);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(config.caret_style, CaretStyle::default());
+        assert_eq!(config.test_mode, TestMode::default());
+        assert!(config.show_typed_chars);
+    }
+
+    #[test]
+    fn test_merge_keeps_unknown_keys() {
+        let existing = r#"{"caret_blink": true, "future_option": [1, 2], "theme": "dark"}"#;
+        let config = Config { caret_blink: false, ..Config::default() };
+
+        let json = config.merge_into_json(Some(existing)).unwrap();
+        let object: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(object["future_option"], serde_json::json!([1, 2]));
+        assert_eq!(object["caret_blink"], Value::Bool(false));
+        assert_eq!(Config::from_json(&json), (config.clone(), Vec::new()));
+
+        assert_eq!(Config::from_json(&config.merge_into_json(None).unwrap()).0, config);
+    }
+
+    #[test]
+    fn test_merge_refuses_invalid_file() {
+        let config = Config::default();
+        assert!(config.merge_into_json(Some("{ not json")).is_err());
+        assert!(config.merge_into_json(Some("[1, 2]")).is_err());
+    }
+
+    #[test]
+    fn test_malformed_json() {
+        let (config, errors) = Config::from_json("{ not json");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(config, Config::default());
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use super::models::Config;
+use crate::profile::storage::{config_dir, get_config_dir};
+
+/// Get the path to the config file (next to profile.json)
+fn get_config_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("config.json"))
+}
+
+/// Save config to disk
+/// The settings are merged into the existing file, keeping keys this version doesn't know.
+/// A file that isn't valid JSON is left alone, so the user's version can still be fixed.
+pub fn save_config(config: &Config) -> Result<()> {
+    get_config_dir()?;
+    let path = get_config_path()?;
+
+    let existing = match fs::read_to_string(&path) {
+        Ok(content) => Some(content),
+        Err(err) if err.kind() == ErrorKind::NotFound => None,
+        Err(err) => return Err(err).with_context(|| format!("Failed to read config from {:?}", path)),
+    };
+    let json = config.merge_into_json(existing.as_deref())
+        .with_context(|| format!("Failed to update config {:?}", path))?;
+
+    fs::write(&path, json)
+        .with_context(|| format!("Failed to write config to {:?}", path))?;
+
+    Ok(())
+}
+
+/// Load config from disk
+/// Returns the default config if the file doesn't exist.
+/// Problems with individual settings are returned alongside the config instead of failing.
+pub fn load_config() -> Result<(Config, Vec<String>)> {
+    let path = get_config_path()?;
+
+    if !path.exists() {
+        return Ok((Config::default(), Vec::new()));
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read config from {:?}", path))?;
+
+    Ok(Config::from_json(&content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_path() {
+        let path = get_config_path().unwrap();
+        assert!(path.ends_with("config.json"));
+    }
+}
@@ -0,0 +1,417 @@
+use serde::{Deserialize, Serialize};
+
+/// Interface language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    English,
+    Polish,
+}
+
+impl Language {
+    /// Get the language name in that language
+    pub fn name(&self) -> &str {
+        match self {
+            Language::English => "English",
+            Language::Polish => "Polski",
+        }
+    }
+
+    /// Get the next language (cycling)
+    pub fn next(&self) -> Language {
+        match self {
+            Language::English => Language::Polish,
+            Language::Polish => Language::English,
+        }
+    }
+
+    /// Get the message catalog for this language
+    pub fn messages(&self) -> &'static Messages {
+        match self {
+            Language::English => &ENGLISH,
+            Language::Polish => &POLISH,
+        }
+    }
+
+    /// Pick a language from a locale string such as "pl_PL.UTF-8"
+    /// Falls back to English for unknown locales
+    pub fn from_locale(locale: &str) -> Self {
+        let code = locale.split(['_', '.', '@', '-']).next().unwrap_or("");
+        match code.to_ascii_lowercase().as_str() {
+            "pl" => Language::Polish,
+            _ => Language::English,
+        }
+    }
+
+    /// Detect the language from LC_ALL, LC_MESSAGES or LANG (first non-empty wins)
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .map(|value| Self::from_locale(&value))
+            .unwrap_or_default()
+    }
+}
+
+/// All user-facing strings of the UI
+pub struct Messages {
+    // Tabs
+    pub app_title: &'static str,
+    pub tab_test: &'static str,
+    pub tab_stats: &'static str,
+    pub tab_options: &'static str,
+
+    // Test view
+    pub loading_test: &'static str,
+    pub type_the_words: &'static str,
+    pub time: &'static str,
+    pub words: &'static str,
+    pub progress: &'static str,
+    pub accuracy: &'static str,
+    pub seed: &'static str,
+    pub start_typing: &'static str,
+    pub type_the_words_hint: &'static str,
+    pub test_finished: &'static str,
+    pub retry_note: &'static str,
+    pub daily_challenge: &'static str,
+    pub daily_streak: &'static str,
+    pub challenge_code: &'static str,
+    pub click_to_focus: &'static str,
+
+    // Stats view
+    pub best_30_seconds: &'static str,
+    pub best_30_words: &'static str,
+    pub date: &'static str,
+    pub unknown: &'static str,
+    pub no_score_yet: &'static str,
+    pub complete_test_line1: &'static str,
+    pub complete_test_line2: &'static str,
+    pub info: &'static str,
+    pub recent_results: &'static str,
+    pub scores_saved: &'static str,
+    pub compete_line1: &'static str,
+    pub compete_line2: &'static str,
+    pub profile_location: &'static str,
+
+    // Options view
+    pub settings: &'static str,
+    pub settings_hint: &'static str,
+    pub settings_editing_hint: &'static str,
+    pub test_mode: &'static str,
+    pub accessibility: &'static str,
+    pub caret: &'static str,
+    pub interface: &'static str,
+    pub mode: &'static str,
+    pub duration_seconds: &'static str,
+    pub word_count: &'static str,
+    pub word_list: &'static str,
+    pub restart_with: &'static str,
+    pub seed_random: &'static str,
+    pub challenge_none: &'static str,
+    pub error_indication: &'static str,
+    pub show_typed_chars: &'static str,
+    pub caret_style: &'static str,
+    pub caret_blink: &'static str,
+    pub language: &'static str,
+    pub focus_mode: &'static str,
+    pub theme: &'static str,
+    pub focus_timer: &'static str,
+    pub on: &'static str,
+    pub off: &'static str,
+
+    // Setting values
+    pub mode_time: &'static str,
+    pub mode_words: &'static str,
+    pub error_style_color: &'static str,
+    pub error_style_underline: &'static str,
+    pub error_style_strikethrough: &'static str,
+    pub error_style_glyph: &'static str,
+    pub caret_block: &'static str,
+    pub caret_underline: &'static str,
+    pub caret_bar: &'static str,
+    pub restart_new_words: &'static str,
+    pub theme_default: &'static str,
+    pub restart_same_words: &'static str,
+
+    // Help popup
+    pub help: &'static str,
+    pub help_global: &'static str,
+    pub help_no_tab_keys: &'static str,
+    pub help_close: &'static str,
+    pub help_editing: &'static str,
+
+    // Action and key descriptions
+    pub action_quit: &'static str,
+    pub action_next_tab: &'static str,
+    pub action_prev_tab: &'static str,
+    pub action_show_test: &'static str,
+    pub action_show_stats: &'static str,
+    pub action_show_options: &'static str,
+    pub action_restart: &'static str,
+    pub action_retry: &'static str,
+    pub action_new_test: &'static str,
+    pub action_help: &'static str,
+    pub action_toggle_focus_mode: &'static str,
+    pub action_toggle_daily: &'static str,
+    pub action_delete_word: &'static str,
+    pub key_next_word: &'static str,
+    pub key_delete_char: &'static str,
+    pub key_select_setting: &'static str,
+    pub key_change_setting: &'static str,
+    pub key_edit_setting: &'static str,
+    pub key_apply_value: &'static str,
+    pub key_cancel_edit: &'static str,
+
+    // Errors
+    pub error: &'static str,
+    pub invalid_number: &'static str,
+    pub word_list_load_failed: &'static str,
+    pub word_list_fallback: &'static str,
+    pub invalid_seed: &'static str,
+    pub invalid_challenge: &'static str,
+    pub challenge_list_not_found: &'static str,
+}
+
+impl Messages {
+    /// Get the label for an on/off value
+    pub fn on_off(&self, value: bool) -> &'static str {
+        if value { self.on } else { self.off }
+    }
+}
+
+/// English message catalog
+pub static ENGLISH: Messages = Messages {
+    app_title: "TermoType - Typing Speed Test",
+    tab_test: "Test",
+    tab_stats: "Stats",
+    tab_options: "Options",
+
+    loading_test: "Loading test...",
+    type_the_words: "Type the words",
+    time: "Time",
+    words: "Words",
+    progress: "Progress",
+    accuracy: "Accuracy",
+    seed: "Seed",
+    start_typing: "Start typing to begin",
+    type_the_words_hint: "Type the words",
+    test_finished: "Test finished!",
+    retry_note: "Retry of the same text",
+    daily_challenge: "Daily challenge",
+    daily_streak: "Daily streak",
+    challenge_code: "Challenge code",
+    click_to_focus: "Click here or press any key to focus",
+
+    best_30_seconds: "Best 30 Seconds",
+    best_30_words: "Best 30 Words",
+    date: "Date",
+    unknown: "Unknown",
+    no_score_yet: "No score yet!",
+    complete_test_line1: "Complete a test to set your",
+    complete_test_line2: "first record.",
+    info: "Info",
+    recent_results: "Recent results",
+    scores_saved: "Your best scores are automatically saved!",
+    compete_line1: "Complete tests in 30-second or 30-word modes",
+    compete_line2: "to compete with your personal bests.",
+    profile_location: "Profile location:",
+
+    settings: "Settings",
+    settings_hint: "↑/↓ j/k: Select | ←/→ h/l: Change | Enter: Edit",
+    settings_editing_hint: "Type a value | Enter: Apply | Esc: Cancel",
+    test_mode: "Test Mode",
+    accessibility: "Accessibility",
+    caret: "Caret",
+    interface: "Interface",
+    mode: "Mode",
+    duration_seconds: "Duration (seconds)",
+    word_count: "Word count",
+    word_list: "Word list",
+    restart_with: "Restart with",
+    seed_random: "random",
+    challenge_none: "none",
+    error_indication: "Error indication",
+    show_typed_chars: "Show typed characters",
+    caret_style: "Caret style",
+    caret_blink: "Caret blink",
+    language: "Language",
+    focus_mode: "Focus mode",
+    theme: "Theme",
+    focus_timer: "Timer in focus mode",
+    on: "On",
+    off: "Off",
+
+    mode_time: "Time",
+    mode_words: "Words",
+    error_style_color: "Color only",
+    error_style_underline: "Underline",
+    error_style_strikethrough: "Strikethrough",
+    error_style_glyph: "Substitute glyph",
+    caret_block: "Block",
+    caret_underline: "Underline",
+    caret_bar: "Bar",
+    restart_new_words: "New words",
+    theme_default: "Default",
+    restart_same_words: "Same words",
+
+    help: "Help",
+    help_global: "Everywhere",
+    help_no_tab_keys: "No keys specific to this tab",
+    help_close: "Press any key to close",
+    help_editing: "While typing a value",
+
+    action_quit: "Quit",
+    action_next_tab: "Next tab",
+    action_prev_tab: "Previous tab",
+    action_show_test: "Go to Test",
+    action_show_stats: "Go to Stats",
+    action_show_options: "Go to Options",
+    action_restart: "Restart test",
+    action_retry: "Retry the same text",
+    action_new_test: "New test with new words",
+    action_help: "Show this help",
+    action_toggle_focus_mode: "Toggle focus mode for this test",
+    action_toggle_daily: "Start or leave the daily challenge",
+    action_delete_word: "Delete the current word",
+    key_next_word: "Next word",
+    key_delete_char: "Delete last character",
+    key_select_setting: "Select setting",
+    key_change_setting: "Change value",
+    key_edit_setting: "Edit or cycle value",
+    key_apply_value: "Apply value",
+    key_cancel_edit: "Cancel editing",
+
+    error: "Error",
+    invalid_number: "Enter a whole number",
+    word_list_load_failed: "Could not load word list",
+    word_list_fallback: "using",
+    invalid_seed: "Enter a number, or leave empty for random words",
+    invalid_challenge: "Invalid challenge code",
+    challenge_list_not_found: "Word list of the challenge not found",
+};
+
+/// Polish message catalog
+pub static POLISH: Messages = Messages {
+    app_title: "TermoType - Test szybkości pisania",
+    tab_test: "Test",
+    tab_stats: "Statystyki",
+    tab_options: "Opcje",
+
+    loading_test: "Ładowanie testu...",
+    type_the_words: "Przepisz słowa",
+    time: "Czas",
+    words: "Słowa",
+    progress: "Postęp",
+    accuracy: "Dokładność",
+    seed: "Ziarno",
+    start_typing: "Zacznij pisać, aby rozpocząć",
+    type_the_words_hint: "Przepisz słowa",
+    test_finished: "Test zakończony!",
+    retry_note: "Powtórka tego samego tekstu",
+    daily_challenge: "Wyzwanie dnia",
+    daily_streak: "Seria wyzwań dnia",
+    challenge_code: "Kod wyzwania",
+    click_to_focus: "Kliknij tutaj lub naciśnij dowolny klawisz, aby wrócić do testu",
+
+    best_30_seconds: "Rekord 30 sekund",
+    best_30_words: "Rekord 30 słów",
+    date: "Data",
+    unknown: "Nieznana",
+    no_score_yet: "Brak wyniku!",
+    complete_test_line1: "Ukończ test, aby ustanowić",
+    complete_test_line2: "swój pierwszy rekord.",
+    info: "Informacje",
+    recent_results: "Ostatnie wyniki",
+    scores_saved: "Twoje rekordy są zapisywane automatycznie!",
+    compete_line1: "Ukończ testy w trybie 30 sekund lub 30 słów,",
+    compete_line2: "aby pobić swoje rekordy.",
+    profile_location: "Lokalizacja profilu:",
+
+    settings: "Ustawienia",
+    settings_hint: "↑/↓ j/k: Wybór | ←/→ h/l: Zmiana | Enter: Edycja",
+    settings_editing_hint: "Wpisz wartość | Enter: Zastosuj | Esc: Anuluj",
+    test_mode: "Tryb testu",
+    accessibility: "Dostępność",
+    caret: "Kursor",
+    interface: "Interfejs",
+    mode: "Tryb",
+    duration_seconds: "Czas trwania (sekundy)",
+    word_count: "Liczba słów",
+    word_list: "Lista słów",
+    restart_with: "Restart z",
+    seed_random: "losowe",
+    challenge_none: "brak",
+    error_indication: "Oznaczanie błędów",
+    show_typed_chars: "Pokazuj wpisane znaki",
+    caret_style: "Styl kursora",
+    caret_blink: "Miganie kursora",
+    language: "Język",
+    focus_mode: "Tryb skupienia",
+    theme: "Motyw",
+    focus_timer: "Czas w trybie skupienia",
+    on: "Wł.",
+    off: "Wył.",
+
+    mode_time: "Czas",
+    mode_words: "Słowa",
+    error_style_color: "Tylko kolor",
+    error_style_underline: "Podkreślenie",
+    error_style_strikethrough: "Przekreślenie",
+    error_style_glyph: "Symbol zastępczy",
+    caret_block: "Blok",
+    caret_underline: "Podkreślenie",
+    caret_bar: "Pionowa kreska",
+    restart_new_words: "Nowymi słowami",
+    theme_default: "Domyślny",
+    restart_same_words: "Tymi samymi słowami",
+
+    help: "Pomoc",
+    help_global: "Wszędzie",
+    help_no_tab_keys: "Brak klawiszy właściwych dla tej karty",
+    help_close: "Naciśnij dowolny klawisz, aby zamknąć",
+    help_editing: "Podczas wpisywania wartości",
+
+    action_quit: "Wyjście",
+    action_next_tab: "Następna karta",
+    action_prev_tab: "Poprzednia karta",
+    action_show_test: "Przejdź do Testu",
+    action_show_stats: "Przejdź do Statystyk",
+    action_show_options: "Przejdź do Opcji",
+    action_restart: "Zacznij test od nowa",
+    action_retry: "Powtórz ten sam tekst",
+    action_new_test: "Nowy test z nowymi słowami",
+    action_help: "Pokaż tę pomoc",
+    action_toggle_focus_mode: "Włącz/wyłącz tryb skupienia w tym teście",
+    action_toggle_daily: "Rozpocznij lub opuść wyzwanie dnia",
+    action_delete_word: "Usuń bieżące słowo",
+    key_next_word: "Następne słowo",
+    key_delete_char: "Usuń ostatni znak",
+    key_select_setting: "Wybór ustawienia",
+    key_change_setting: "Zmiana wartości",
+    key_edit_setting: "Edycja lub zmiana wartości",
+    key_apply_value: "Zastosuj wartość",
+    key_cancel_edit: "Anuluj edycję",
+
+    error: "Błąd",
+    invalid_number: "Podaj liczbę całkowitą",
+    word_list_load_failed: "Nie udało się wczytać listy słów",
+    word_list_fallback: "używam",
+    invalid_seed: "Podaj liczbę lub zostaw puste dla losowych słów",
+    invalid_challenge: "Nieprawidłowy kod wyzwania",
+    challenge_list_not_found: "Nie znaleziono listy słów wyzwania",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(Language::from_locale("pl_PL.UTF-8"), Language::Polish);
+        assert_eq!(Language::from_locale("en_US.UTF-8"), Language::English);
+        assert_eq!(Language::from_locale("C"), Language::English);
+        assert_eq!(Language::from_locale(""), Language::English);
+    }
+}
@@ -0,0 +1,474 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::i18n::Messages;
+
+/// A named action that can be bound to keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Action {
+    /// Quit the application
+    Quit,
+    /// Switch to the next tab
+    NextTab,
+    /// Switch to the previous tab
+    PrevTab,
+    /// Go to the Test tab
+    ShowTest,
+    /// Go to the Stats tab
+    ShowStats,
+    /// Go to the Options tab
+    ShowOptions,
+    /// Restart the current test, with new or the same words depending on the settings
+    Restart,
+    /// Retry the same words
+    Retry,
+    /// Start a new test with new words
+    NewTest,
+    /// Delete everything typed in the current word
+    DeleteWord,
+    /// Show the help popup
+    Help,
+    /// Turn focus mode on or off
+    ToggleFocusMode,
+    /// Start or leave the daily challenge
+    ToggleDaily,
+}
+
+impl Action {
+    /// Get all actions
+    pub fn all() -> Vec<Action> {
+        vec![
+            Action::Quit,
+            Action::NextTab,
+            Action::PrevTab,
+            Action::ShowTest,
+            Action::ShowStats,
+            Action::ShowOptions,
+            Action::Restart,
+            Action::Retry,
+            Action::NewTest,
+            Action::DeleteWord,
+            Action::Help,
+            Action::ToggleFocusMode,
+            Action::ToggleDaily,
+        ]
+    }
+
+    /// Get the name used for the action in config.json
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::NextTab => "next_tab",
+            Action::PrevTab => "prev_tab",
+            Action::ShowTest => "show_test",
+            Action::ShowStats => "show_stats",
+            Action::ShowOptions => "show_options",
+            Action::Restart => "restart",
+            Action::Retry => "retry",
+            Action::NewTest => "new_test",
+            Action::DeleteWord => "delete_word",
+            Action::Help => "help",
+            Action::ToggleFocusMode => "toggle_focus_mode",
+            Action::ToggleDaily => "toggle_daily",
+        }
+    }
+
+    /// Find an action by its config name
+    pub fn from_config_name(name: &str) -> Option<Action> {
+        Action::all().into_iter().find(|action| action.config_name() == name)
+    }
+
+    /// Get the description of the action shown in the help popup
+    pub fn description(&self, msg: &Messages) -> &'static str {
+        match self {
+            Action::Quit => msg.action_quit,
+            Action::NextTab => msg.action_next_tab,
+            Action::PrevTab => msg.action_prev_tab,
+            Action::ShowTest => msg.action_show_test,
+            Action::ShowStats => msg.action_show_stats,
+            Action::ShowOptions => msg.action_show_options,
+            Action::Restart => msg.action_restart,
+            Action::Retry => msg.action_retry,
+            Action::NewTest => msg.action_new_test,
+            Action::DeleteWord => msg.action_delete_word,
+            Action::Help => msg.action_help,
+            Action::ToggleFocusMode => msg.action_toggle_focus_mode,
+            Action::ToggleDaily => msg.action_toggle_daily,
+        }
+    }
+
+    /// Check if the action works on every tab
+    /// Other actions only apply to the Test tab, so their keys keep their meaning elsewhere.
+    /// Either way, a key that types text (e.g. 'q' or '?') is input while a test can be typed,
+    /// so it only triggers its action on the other tabs or once the test is finished.
+    pub fn is_global(&self) -> bool {
+        !matches!(self, Action::Restart | Action::Retry | Action::NewTest | Action::DeleteWord)
+    }
+
+    /// Get the keys bound to the action by default
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q", "esc", "ctrl+c", "ctrl+q"],
+            Action::NextTab => &["tab"],
+            Action::PrevTab => &["shift+tab"],
+            Action::ShowTest => &["1"],
+            Action::ShowStats => &["2"],
+            Action::ShowOptions => &["3"],
+            Action::Restart => &["enter"],
+            Action::Retry => &["ctrl+r"],
+            Action::NewTest => &["ctrl+n"],
+            // Not Ctrl+H: terminals whose Backspace sends ^H report it as Ctrl+H, so every Backspace
+            // would delete the whole word. Where Ctrl+Backspace arrives as Ctrl+H, bind "ctrl+h" in config.json.
+            Action::DeleteWord => &["ctrl+backspace", "ctrl+w", "alt+backspace"],
+            Action::Help => &["?", "f1"],
+            Action::ToggleFocusMode => &["ctrl+f"],
+            Action::ToggleDaily => &["ctrl+d"],
+        }
+    }
+}
+
+/// A key together with its modifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Create a binding, normalizing modifiers the terminal reports inconsistently
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // Shift is already part of a typed character ('Q', '?') and of BackTab
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        // Terminals report Ctrl/Alt + letter in lowercase
+        let code = match code {
+            KeyCode::Char(ch) if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                KeyCode::Char(ch.to_ascii_lowercase())
+            }
+            code => code,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Parse a binding such as "q", "esc", "ctrl+c" or "shift+tab"
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let (modifier_part, key_part) = match text.strip_suffix("++") {
+            // "ctrl++" binds the '+' key
+            Some(prefix) => (prefix, "+"),
+            None if text == "+" => ("", "+"),
+            None => text.rsplit_once('+').unwrap_or(("", text)),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in modifier_part.split('+').filter(|m| !m.is_empty()) {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier '{}' in '{}'", modifier, text)),
+            };
+        }
+
+        let mut chars = key_part.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(ch), None) => KeyCode::Char(ch),
+            _ => match key_part.to_ascii_lowercase().as_str() {
+                "esc" | "escape" => KeyCode::Esc,
+                "enter" | "return" => KeyCode::Enter,
+                "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "space" => KeyCode::Char(' '),
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(format!("unknown key '{}'", text)),
+                },
+            },
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+
+    /// Check if the key types a character, so it is text input while a test is being typed
+    pub fn types_text(&self) -> bool {
+        typed_char(&KeyEvent::new(self.code, self.modifiers)).is_some()
+    }
+}
+
+impl From<&KeyEvent> for KeyBinding {
+    fn from(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(ch) => write!(f, "{}", ch),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// Get the character a key press types, if it is text input
+/// Ctrl or Alt alone make a shortcut, but both together are AltGr on Windows (e.g. 'ą', 'ł'),
+/// which is text.
+pub fn typed_char(key: &KeyEvent) -> Option<char> {
+    let KeyCode::Char(ch) = key.code else {
+        return None;
+    };
+
+    let shortcut = key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        && !key.modifiers.contains(KeyModifiers::CONTROL | KeyModifiers::ALT);
+    (!shortcut && !ch.is_control()).then_some(ch)
+}
+
+/// Mapping from keys to actions
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    /// Keys bound to each action
+    bindings: BTreeMap<Action, Vec<KeyBinding>>,
+    /// Overrides from config.json, kept so they can be saved back unchanged
+    overrides: BTreeMap<String, Vec<String>>,
+}
+
+impl Keymap {
+    /// Build the keymap from the defaults and the overrides in config.json
+    /// An override replaces all default keys of its action.
+    /// Returns the keymap and a description of every invalid entry or conflict;
+    /// invalid entries are skipped. An override takes its keys away from the defaults of other actions;
+    /// only two overrides sharing a key are a conflict, and the first of them wins.
+    /// An override that types text conflicts with typing the test: it is kept for the other tabs,
+    /// but reported as it does nothing while a test is being typed.
+    pub fn from_config(overrides: &BTreeMap<String, Vec<String>>) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut bindings: BTreeMap<Action, Vec<KeyBinding>> = Action::all()
+            .into_iter()
+            .map(|action| {
+                let keys = action.default_keys().iter().filter_map(|key| KeyBinding::parse(key).ok()).collect();
+                (action, keys)
+            })
+            .collect();
+
+        for (name, keys) in overrides {
+            let Some(action) = Action::from_config_name(name) else {
+                errors.push(format!("unknown action '{}'", name));
+                continue;
+            };
+
+            let parsed = keys
+                .iter()
+                .filter_map(|key| KeyBinding::parse(key).map_err(|err| errors.push(err)).ok())
+                .collect();
+            bindings.insert(action, parsed);
+        }
+
+        // A key can only trigger one action. The overrides claim their keys first, so a key moved to
+        // another action is silently taken away from the action it was a default of.
+        let overridden = |action: &Action| overrides.contains_key(action.config_name());
+        let mut owners: HashMap<KeyBinding, Action> = HashMap::new();
+        for explicit in [true, false] {
+            for (action, keys) in bindings.iter_mut().filter(|(action, _)| overridden(action) == explicit) {
+                keys.retain(|key| match owners.get(key) {
+                    Some(owner) if owner != action => {
+                        if explicit {
+                            errors.push(format!(
+                                "'{}' is bound to both {} and {}",
+                                key,
+                                owner.config_name(),
+                                action.config_name()
+                            ));
+                        }
+                        false
+                    }
+                    _ => {
+                        owners.insert(*key, *action);
+                        true
+                    }
+                });
+            }
+        }
+
+        for (action, keys) in &bindings {
+            if !overrides.contains_key(action.config_name()) {
+                continue;
+            }
+            for key in keys.iter().filter(|key| key.types_text()) {
+                errors.push(format!(
+                    "'{}' types text, so {} doesn't work while a test is being typed",
+                    key,
+                    action.config_name()
+                ));
+            }
+        }
+
+        (Self { bindings, overrides: overrides.clone() }, errors)
+    }
+
+    /// Get the action bound to a key event
+    pub fn action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from(key);
+        self.bindings
+            .iter()
+            .find(|(_, keys)| keys.contains(&binding))
+            .map(|(action, _)| *action)
+    }
+
+    /// Get the keys bound to an action
+    pub fn keys(&self, action: Action) -> &[KeyBinding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Get the keys bound to an action for display, e.g. "q/Esc"
+    /// Returns None if the action has no keys
+    pub fn key_label(&self, action: Action) -> Option<String> {
+        let keys = self.keys(action);
+        (!keys.is_empty()).then(|| keys.iter().map(KeyBinding::to_string).collect::<Vec<_>>().join("/"))
+    }
+
+    /// Get the overrides to store in config.json
+    pub fn overrides(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.overrides
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&BTreeMap::new()).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_bindings() {
+        assert_eq!(KeyBinding::parse("q").unwrap(), KeyBinding::new(KeyCode::Char('q'), KeyModifiers::NONE));
+        assert_eq!(KeyBinding::parse("Ctrl+C").unwrap(), KeyBinding::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert_eq!(KeyBinding::parse("shift+tab").unwrap(), KeyBinding::new(KeyCode::BackTab, KeyModifiers::NONE));
+        assert_eq!(KeyBinding::parse("f1").unwrap(), KeyBinding::new(KeyCode::F(1), KeyModifiers::NONE));
+        assert_eq!(KeyBinding::parse("ctrl++").unwrap(), KeyBinding::new(KeyCode::Char('+'), KeyModifiers::CONTROL));
+        assert!(KeyBinding::parse("hyper+x").is_err());
+        assert!(KeyBinding::parse("f13").is_err());
+    }
+
+    #[test]
+    fn test_display_binding() {
+        assert_eq!(KeyBinding::parse("ctrl+w").unwrap().to_string(), "Ctrl+w");
+        assert_eq!(KeyBinding::parse("esc").unwrap().to_string(), "Esc");
+        assert_eq!(KeyBinding::parse("shift+tab").unwrap().to_string(), "Shift+Tab");
+    }
+
+    #[test]
+    fn test_default_keymap() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.action(&press(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::Quit));
+        assert_eq!(keymap.action(&press(KeyCode::BackTab, KeyModifiers::SHIFT)), Some(Action::PrevTab));
+        assert_eq!(keymap.action(&press(KeyCode::Char('x'), KeyModifiers::NONE)), None);
+        assert_eq!(keymap.action(&press(KeyCode::Char('?'), KeyModifiers::SHIFT)), Some(Action::Help));
+        assert_eq!(keymap.action(&press(KeyCode::Char('c'), KeyModifiers::CONTROL)), Some(Action::Quit));
+        assert_eq!(keymap.action(&press(KeyCode::Backspace, KeyModifiers::ALT)), Some(Action::DeleteWord));
+        // Backspace on terminals that send ^H
+        assert_eq!(keymap.action(&press(KeyCode::Char('h'), KeyModifiers::CONTROL)), None);
+        assert_eq!(keymap.key_label(Action::Quit).as_deref(), Some("q/Esc/Ctrl+c/Ctrl+q"));
+    }
+
+    #[test]
+    fn test_override_replaces_defaults() {
+        let (keymap, errors) = Keymap::from_config(&BTreeMap::from([
+            ("quit".to_string(), vec!["ctrl+q".to_string()]),
+            ("restart".to_string(), vec!["esc".to_string()]),
+        ]));
+
+        assert!(errors.is_empty());
+        assert_eq!(keymap.action(&press(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::Restart));
+        assert_eq!(keymap.action(&press(KeyCode::Char('q'), KeyModifiers::CONTROL)), Some(Action::Quit));
+        assert_eq!(keymap.action(&press(KeyCode::Enter, KeyModifiers::NONE)), None);
+    }
+
+    #[test]
+    fn test_conflicts_and_invalid_entries_are_reported() {
+        let (keymap, errors) = Keymap::from_config(&BTreeMap::from([
+            ("quit".to_string(), vec!["ctrl+q".to_string()]),
+            ("restart".to_string(), vec!["ctrl+q".to_string(), "bogus key".to_string()]),
+            ("fly".to_string(), vec!["f".to_string()]),
+        ]));
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors.contains(&"'Ctrl+q' is bound to both quit and restart".to_string()));
+        // Quit comes first, so it keeps Ctrl+Q
+        assert_eq!(keymap.action(&press(KeyCode::Char('q'), KeyModifiers::CONTROL)), Some(Action::Quit));
+    }
+
+    #[test]
+    fn test_override_takes_keys_from_defaults() {
+        let (keymap, errors) =
+            Keymap::from_config(&BTreeMap::from([("restart".to_string(), vec!["esc".to_string()])]));
+
+        assert!(errors.is_empty());
+        assert_eq!(keymap.action(&press(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::Restart));
+        assert_eq!(keymap.key_label(Action::Quit).as_deref(), Some("q/Ctrl+c/Ctrl+q"));
+    }
+
+    #[test]
+    fn test_text_keys_are_reported() {
+        let (keymap, errors) = Keymap::from_config(&BTreeMap::from([
+            ("restart".to_string(), vec!["r".to_string(), "ctrl+r".to_string()]),
+            ("retry".to_string(), vec!["alt+r".to_string()]),
+        ]));
+
+        assert_eq!(errors, ["'r' types text, so restart doesn't work while a test is being typed"]);
+        // Still bound, for when no test is being typed
+        assert_eq!(keymap.action(&press(KeyCode::Char('r'), KeyModifiers::NONE)), Some(Action::Restart));
+        assert!(KeyBinding::parse("?").unwrap().types_text());
+        assert!(!KeyBinding::parse("ctrl+q").unwrap().types_text());
+        assert!(!KeyBinding::parse("f1").unwrap().types_text());
+    }
+
+    #[test]
+    fn test_typed_char() {
+        assert_eq!(typed_char(&press(KeyCode::Char('a'), KeyModifiers::NONE)), Some('a'));
+        assert_eq!(typed_char(&press(KeyCode::Char('A'), KeyModifiers::SHIFT)), Some('A'));
+        // AltGr
+        assert_eq!(typed_char(&press(KeyCode::Char('ą'), KeyModifiers::CONTROL | KeyModifiers::ALT)), Some('ą'));
+        assert_eq!(typed_char(&press(KeyCode::Char('c'), KeyModifiers::CONTROL)), None);
+        assert_eq!(typed_char(&press(KeyCode::Char('x'), KeyModifiers::ALT)), None);
+        assert_eq!(typed_char(&press(KeyCode::Enter, KeyModifiers::NONE)), None);
+    }
+}
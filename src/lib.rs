@@ -0,0 +1,28 @@
+//! Scoring logic and profile format behind the termotype typing test
+//!
+//! The `termotype` binary is a terminal front-end on top of this crate.
+//! Other front-ends and analysis tools can use the same engine, metrics and profile storage:
+//!
+//! ```
+//! use termotype::{TestEngine, TestMode, TestState};
+//!
+//! let mut engine = TestEngine::new(TestMode::Words(2), vec!["hello".into(), "world".into()]);
+//! for word in ["hello", "wrld"] {
+//!     word.chars().for_each(|ch| engine.type_char(ch));
+//!     engine.next_word();
+//! }
+//!
+//! assert_eq!(engine.state, TestState::Finished);
+//! assert_eq!(engine.correct_chars, 6);
+//! ```
+
+pub mod profile;
+pub mod report;
+pub mod test;
+
+pub use profile::{BestScore, DailyResult, Profile, TestResult};
+pub use test::{
+    Challenge, CharState, Clock, DEFAULT_WORD_LIST, DailyChallenge, MAX_SECONDS, MAX_WORDS, ManualClock, Script,
+    SystemClock, TestEngine, TestMetrics, TestMode, TestState, WordListEntry, WordListSource, WordLists, WordState,
+    generate_word_sequence, load_text_from_file, load_words, load_words_from_file, seeded_rng,
+};
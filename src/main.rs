@@ -1,176 +1,400 @@
 mod app;
+mod cli;
+mod config;
+mod i18n;
+mod keymap;
+mod settings;
+mod tty;
 mod ui;
-mod test;
-mod profile;
+
+// The scoring logic and profile format live in the library
+use termotype::{profile, report, test};
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    cursor::SetCursorStyle,
+    event::{
+        self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
+    layout::Rect,
     Terminal,
 };
-use std::io;
 
 use app::App;
+use report::RunStatus;
+
+/// Exit status when the app stopped with an error
+const EXIT_FAILED: i32 = 1;
+/// Exit status for an invalid command line
+const EXIT_USAGE: i32 = 2;
+/// Exit status when the test was quit before the end (with --print-result or --json)
+const EXIT_ABORTED: i32 = 3;
+/// Exit status when the terminal was hung up (128 + SIGHUP, as a shell reports it)
+const EXIT_HANGUP: i32 = 129;
 
 fn main() -> Result<()> {
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let args = match cli::parse(std::env::args().skip(1)) {
+        Ok(cli::Command::Run(args)) => args,
+        Ok(cli::Command::Stats(report)) => {
+            let today = chrono::Utc::now().date_naive();
+            return print_report(&report, |profile| {
+                if report.json { report::stats_json(profile, today) } else { report::stats_table(profile, today) }
+            });
+        }
+        Ok(cli::Command::History(report)) => {
+            let limit = report.limit.unwrap_or(cli::DEFAULT_HISTORY_LIMIT);
+            return print_report(&report, |profile| match (report.daily, report.json) {
+                (true, true) => report::daily_json(profile, limit),
+                (true, false) => report::daily_table(profile, limit),
+                (false, true) => report::history_json(profile, limit),
+                (false, false) => report::history_table(profile, limit),
+            });
+        }
+        Ok(cli::Command::Help) => {
+            println!("{}", cli::usage());
+            return Ok(());
+        }
+        Ok(cli::Command::StatsHelp) => {
+            println!("{}", cli::stats_usage());
+            return Ok(());
+        }
+        Ok(cli::Command::HistoryHelp) => {
+            println!("{}", cli::history_usage());
+            return Ok(());
+        }
+        Ok(cli::Command::Version) => {
+            println!("termotype {}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        Err(err) => {
+            eprintln!("termotype: {}\nTry 'termotype --help' for more information.", err);
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+
+    // The profile directory has to be known before anything is loaded
+    if let Some(dir) = &args.profile_dir {
+        profile::storage::set_config_dir(dir.clone());
+    }
 
     // Create app state
     let mut app = App::new();
+    if let Err(err) = app.apply_args(&args) {
+        eprintln!("termotype: {}", err);
+        std::process::exit(EXIT_USAGE);
+    }
     app.init_test();
 
+    // Setup terminal (and make sure a panic gives it back)
+    if args.prints_result() {
+        tty::draw_on_stderr();
+    }
+    tty::install_panic_hook();
+    tty::setup()?;
+    let backend = CrosstermBackend::new(tty::output());
+    let mut terminal = Terminal::new(backend)?;
+
     // Run the application
     let res = run_app(&mut terminal, &mut app);
 
+    // A hung-up terminal can't be restored, and there is no one left to read an error
+    if matches!(res, Ok(true)) {
+        let _ = tty::restore();
+        std::process::exit(EXIT_HANGUP);
+    }
+
     // Cleanup terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    tty::restore()?;
 
     // Print any errors that occurred
     if let Err(err) = res {
-        eprintln!("Error: {:?}", err);
+        eprintln!("{}: {:?}", app.messages().error, err);
+        std::process::exit(EXIT_FAILED);
+    }
+
+    // Print the result now that the alternate screen is gone
+    if args.prints_result()
+        && let Some(summary) = app.run_summary()
+    {
+        println!("{}", if args.json { summary.json() } else { summary.line() });
+        if summary.status == RunStatus::Aborted {
+            std::process::exit(EXIT_ABORTED);
+        }
     }
 
     Ok(())
 }
 
+/// Print a report of the saved profile without starting the interface
+/// Nothing is written, so a mistyped --profile-dir is reported instead of being created.
+fn print_report(report: &cli::ReportArgs, format: impl Fn(&profile::Profile) -> String) -> Result<()> {
+    if let Some(dir) = &report.profile_dir {
+        if !dir.is_dir() {
+            eprintln!("termotype: profile directory {} doesn't exist", dir.display());
+            std::process::exit(EXIT_FAILED);
+        }
+        profile::storage::set_config_dir(dir.clone());
+    }
+    let profile = profile::load_profile()?;
+    println!("{}", format(&profile));
+    Ok(())
+}
+
 /// Main application loop
+/// Returns whether it ended because the terminal was hung up.
 fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
-) -> Result<()> {
+) -> Result<bool> {
+    // Cursor shape currently applied to the terminal
+    let mut applied_caret_style = None;
+
+    #[cfg(unix)]
+    let mut signals = tty::Signals::new()?;
+
     while app.running {
+        // The bar caret uses the real terminal cursor, so give it a bar shape
+        if applied_caret_style != Some(app.caret_style) {
+            let shape = if app.caret_style == ui::CaretStyle::Bar {
+                SetCursorStyle::SteadyBar
+            } else {
+                SetCursorStyle::DefaultUserShape
+            };
+            execute!(tty::output(), shape)?;
+            applied_caret_style = Some(app.caret_style);
+        }
+
         // Draw UI
-        terminal.draw(|f| {
-            ui(f, app);
-        })?;
+        let caret_visible = app.caret_visible();
+        terminal.draw(|f| ui::draw(f, app, caret_visible))?;
 
         // Handle events
         if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
+            match event::read()? {
+                // Raw mode turns Ctrl+Z into a key press instead of SIGTSTP
+                #[cfg(unix)]
+                Event::Key(key)
+                    if key.kind == KeyEventKind::Press
+                        && key.code == KeyCode::Char('z')
+                        && key.modifiers == KeyModifiers::CONTROL =>
+                {
+                    suspend(terminal, &mut applied_caret_style)?;
+                }
                 // Only process KeyPress events, ignore KeyRelease
-                if key.kind == KeyEventKind::Press {
-                    // Global keybindings
-                    match key.code {
-                        KeyCode::Char('q') | KeyCode::Esc => {
-                            app.quit();
-                            continue;
-                        }
-                        KeyCode::Tab => {
-                            app.next_tab();
-                            continue;
-                        }
-                        KeyCode::BackTab => {
-                            app.prev_tab();
-                            continue;
-                        }
-                        KeyCode::Char('1') => {
-                            app.current_tab = ui::Tab::Test;
-                            continue;
-                        }
-                        KeyCode::Char('2') => {
-                            app.current_tab = ui::Tab::Stats;
-                            continue;
-                        }
-                        KeyCode::Char('3') => {
-                            app.current_tab = ui::Tab::Options;
-                            continue;
-                        }
-                        _ => {}
-                    }
+                Event::Key(key) if key.kind == KeyEventKind::Press => handle_key(app, key),
+                Event::Mouse(mouse) => {
+                    let size = terminal.size()?;
+                    handle_mouse(app, mouse, Rect::new(0, 0, size.width, size.height));
+                }
+                Event::FocusLost => app.focused = false,
+                _ => {}
+            }
+        }
 
-                    // Tab-specific keybindings
-                    if app.current_tab == ui::Tab::Test {
-                        let mut should_reset = false;
-
-                        if let Some(engine) = &mut app.test_engine {
-                            match key.code {
-                                KeyCode::Char(ch) => {
-                                    // Only handle Space specially, other chars are normal input
-                                    if ch == ' ' {
-                                        engine.next_word();
-                                    } else {
-                                        engine.type_char(ch);
-                                    }
-                                }
-                                KeyCode::Backspace => {
-                                    engine.backspace();
-                                }
-                                KeyCode::Enter => {
-                                    // Reset test on Enter
-                                    should_reset = true;
-                                }
-                                _ => {}
-                            }
-
-                            // Check if test should auto-finish
-                            let was_in_progress = engine.state == crate::test::TestState::InProgress;
-                            if engine.should_auto_finish() && was_in_progress {
-                                engine.finish();
-                            }
-                        }
+        #[cfg(unix)]
+        match signals.check() {
+            tty::SignalAction::Exit => {
+                app.save_state();
+                app.quit();
+            }
+            tty::SignalAction::HangUp => {
+                app.save_state();
+                return Ok(true);
+            }
+            tty::SignalAction::Suspend => suspend(terminal, &mut applied_caret_style)?,
+            tty::SignalAction::None => {}
+        }
 
-                        // Save result after test finishes (outside the borrow)
-                        if let Some(engine) = &app.test_engine {
-                            if engine.state == crate::test::TestState::Finished {
-                                // Only save once per test completion
-                                app.save_test_result();
-                            }
-                        }
+        // A time test can run out without any input
+        app.tick();
+    }
 
-                        // Reset outside of the borrow
-                        if should_reset {
-                            app.reset_test();
-                        }
-                    }
+    Ok(false)
+}
+
+/// Stop the process until it is resumed, then redraw everything
+#[cfg(unix)]
+fn suspend<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    applied_caret_style: &mut Option<ui::CaretStyle>,
+) -> Result<()> {
+    tty::suspend()?;
+    // The shell may have changed the cursor shape and the screen contents meanwhile
+    *applied_caret_style = None;
+    terminal.clear()?;
+    Ok(())
+}
+
+/// Handle a key press
+fn handle_key(app: &mut App, key: KeyEvent) {
+    // Keep the caret solid while typing
+    app.restart_caret_blink();
+    app.focused = true;
+
+    // Ctrl+C always quits, even if the quit action is bound to other keys
+    if key.code == KeyCode::Char('c') && key.modifiers == KeyModifiers::CONTROL {
+        app.quit();
+        return;
+    }
+
+    // Any key closes the help popup
+    if app.show_help {
+        app.show_help = false;
+        return;
+    }
+
+    // Typing a setting value captures every key, so it comes before the global keybindings
+    if app.current_tab == ui::Tab::Options && app.settings_menu.is_editing() {
+        handle_setting_input(app, key);
+        return;
+    }
+
+    // Named actions from the keymap (test-only ones fall through on other tabs,
+    // and keys that type text are input while the test can be typed)
+    let typing = app.accepts_typing() && keymap::typed_char(&key).is_some();
+    if !typing
+        && let Some(action) = app.keymap.action(&key)
+        && (action.is_global() || app.current_tab == ui::Tab::Test)
+    {
+        app.perform(action);
+        return;
+    }
+
+    // Tab-specific keybindings
+    if app.current_tab == ui::Tab::Test {
+        if let Some(engine) = &mut app.test_engine {
+            match (key.code, keymap::typed_char(&key)) {
+                // Only handle Space specially, other chars are normal input
+                (_, Some(' ')) => engine.next_word(),
+                (_, Some(ch)) => engine.type_char(ch),
+                (KeyCode::Backspace, _) => engine.backspace(),
+                _ => {}
+            }
+        }
+
+        app.tick();
+    }
+
+    // Options tab keybindings
+    if app.current_tab == ui::Tab::Options {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => app.settings_menu.select_prev(),
+            KeyCode::Down | KeyCode::Char('j') => app.settings_menu.select_next(),
+            KeyCode::Left | KeyCode::Char('h') => app.adjust_setting(false),
+            KeyCode::Right | KeyCode::Char('l') => app.adjust_setting(true),
+            KeyCode::Enter => app.activate_setting(),
+            _ => {}
+        }
+    }
+}
 
-                    // Options tab keybindings
-                    if app.current_tab == ui::Tab::Options {
-                        match key.code {
-                            KeyCode::Char('t') => {
-                                app.set_time_mode();
-                            }
-                            KeyCode::Char('w') => {
-                                app.set_words_mode();
-                            }
-                            _ => {}
+/// Handle a key press while typing a setting value
+fn handle_setting_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => app.confirm_setting_input(),
+        KeyCode::Esc => app.cancel_setting_input(),
+        KeyCode::Backspace => {
+            if let Some(input) = &mut app.settings_menu.input {
+                input.pop();
+            }
+        }
+        _ => {
+            if let Some(ch) = keymap::typed_char(&key)
+                && let Some(input) = &mut app.settings_menu.input
+            {
+                input.push(ch);
+            }
+        }
+    }
+}
+
+/// Handle a mouse event
+fn handle_mouse(app: &mut App, mouse: MouseEvent, screen: Rect) {
+    let (tabs_area, content_area) = ui::split_screen(screen);
+    let (column, row) = (mouse.column, mouse.row);
+
+    // A click anywhere closes the help popup
+    if app.show_help {
+        if let MouseEventKind::Down(_) = mouse.kind {
+            app.show_help = false;
+        }
+        return;
+    }
+
+    // Focus mode only shows the words, so a click can only bring the focus back
+    if app.focus_active() {
+        if let MouseEventKind::Down(_) = mouse.kind {
+            app.restart_caret_blink();
+            app.focused = true;
+        }
+        return;
+    }
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(tab) = ui::tabs::tab_at(tabs_area, column, row, app.messages()) {
+                app.set_tab(tab);
+                return;
+            }
+
+            match app.current_tab {
+                ui::Tab::Test => {
+                    if ui::test_view::words_area_contains(content_area, column, row) {
+                        app.restart_caret_blink();
+                        app.focused = true;
+                    }
+                }
+                ui::Tab::Options => {
+                    if let Some(index) = ui::options_view::setting_at(app, content_area, column, row) {
+                        // Clicking the selected setting again activates it
+                        if index == app.settings_menu.selected {
+                            app.activate_setting();
+                        } else {
+                            app.settings_menu.select(index);
                         }
                     }
                 }
+                ui::Tab::Stats => {}
             }
         }
+        MouseEventKind::ScrollDown if app.current_tab == ui::Tab::Options => {
+            app.settings_menu.select_next();
+        }
+        MouseEventKind::ScrollUp if app.current_tab == ui::Tab::Options => {
+            app.settings_menu.select_prev();
+        }
+        MouseEventKind::ScrollDown | MouseEventKind::ScrollUp if app.current_tab == ui::Tab::Stats => {
+            let down = mouse.kind == MouseEventKind::ScrollDown;
+            app.scroll_history(down, ui::stats_view::history_rows(content_area));
+        }
+        _ => {}
     }
-
-    Ok(())
 }
 
-/// Render the UI
-fn ui(f: &mut ratatui::Frame, app: &App) {
-    // Split screen into tab bar and content area
-    let (tabs_area, content_area) = ui::split_screen(f.area());
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use test::TestState;
 
-    // Render tabs
-    ui::render_tabs(f, tabs_area, app.current_tab);
+    #[test]
+    fn test_text_keys_are_typed_during_a_test() {
+        let mut app = App::with_state(Config::default(), Vec::new(), profile::Profile::new());
+        app.init_test();
 
-    // Render content based on current tab
-    match app.current_tab {
-        ui::Tab::Test => crate::ui::test_view::render(f, app, content_area),
-        ui::Tab::Stats => crate::ui::stats_view::render(f, app, content_area),
-        ui::Tab::Options => crate::ui::options_view::render(f, app, content_area),
+        // "q", "?" and the digits are bound to quit, help and the tabs
+        for ch in "quite? 123".chars() {
+            handle_key(&mut app, KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        assert!(app.running);
+        assert!(!app.show_help);
+        assert_eq!(app.current_tab, ui::Tab::Test);
+        let engine = app.test_engine.as_ref().unwrap();
+        assert_eq!(engine.state, TestState::InProgress);
+        assert_eq!(engine.current_word_index, 1);
     }
 }
@@ -1,5 +1,5 @@
 pub mod models;
 pub mod storage;
 
-pub use models::{Profile, BestScore};
+pub use models::{best_score_key, Profile, BestScore, DailyResult, TestResult};
 pub use storage::{save_profile, load_profile};
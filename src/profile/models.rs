@@ -1,6 +1,29 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::SystemTime;
 
+use crate::test::{TestMode, DEFAULT_WORD_LIST};
+
+/// Most results kept in the history (older ones are dropped)
+const MAX_HISTORY: usize = 1000;
+
+/// Get the current Unix timestamp
+fn now_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// Get the key of the best score of a mode on a word list (e.g. `time_30:english`)
+pub fn best_score_key(mode: &TestMode, word_list: &str) -> String {
+    match mode {
+        TestMode::Time(seconds) => format!("time_{}:{}", seconds, word_list),
+        TestMode::Words(count) => format!("words_{}:{}", count, word_list),
+    }
+}
+
 /// Represents a best score for a specific test mode
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BestScore {
@@ -17,16 +40,11 @@ pub struct BestScore {
 impl BestScore {
     /// Create a new best score
     pub fn new(wpm: f64, cpm: f64, accuracy: f64) -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
         Self {
             wpm,
             cpm,
             accuracy,
-            timestamp,
+            timestamp: now_timestamp(),
         }
     }
 
@@ -36,55 +54,184 @@ impl BestScore {
     }
 }
 
+/// A finished test in the history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestResult {
+    /// Mode the test was run in
+    pub mode: TestMode,
+    /// Words per minute
+    pub wpm: f64,
+    /// Characters per minute
+    pub cpm: f64,
+    /// Accuracy percentage
+    pub accuracy: f64,
+    /// When the test was finished (Unix timestamp)
+    pub timestamp: u64,
+    /// Seed of the words, to retake the same test (None for a fixed text)
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Name of the word list (None for a fixed text or quote, and for older results)
+    #[serde(default)]
+    pub word_list: Option<String>,
+}
+
+impl TestResult {
+    /// Create a result finished now
+    pub fn new(mode: TestMode, wpm: f64, cpm: f64, accuracy: f64, seed: Option<u64>, word_list: Option<String>) -> Self {
+        Self {
+            mode,
+            wpm,
+            cpm,
+            accuracy,
+            timestamp: now_timestamp(),
+            seed,
+            word_list,
+        }
+    }
+}
+
+/// Best result of a daily challenge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyResult {
+    /// Day of the challenge (`YYYY-MM-DD`)
+    pub date: String,
+    /// The result itself
+    #[serde(flatten)]
+    pub result: TestResult,
+}
+
+impl DailyResult {
+    /// Create a daily result for the challenge of a given day
+    pub fn new(date: NaiveDate, result: TestResult) -> Self {
+        Self {
+            date: date.format("%Y-%m-%d").to_string(),
+            result,
+        }
+    }
+
+    /// Get the day of the challenge
+    pub fn day(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.date, "%Y-%m-%d").ok()
+    }
+}
+
 /// User profile with best scores
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Profile {
-    /// Best score for 30 seconds mode
-    pub best_30_seconds: Option<BestScore>,
-    /// Best score for 30 words mode
-    pub best_30_words: Option<BestScore>,
+    /// Best scores of the tracked modes, keyed by mode and word list (see `best_score_key`)
+    #[serde(default)]
+    pub best_scores: BTreeMap<String, BestScore>,
+    /// Best score for 30 seconds mode, from before scores were kept per word list
+    #[serde(default, skip_serializing)]
+    best_30_seconds: Option<BestScore>,
+    /// Best score for 30 words mode, from before scores were kept per word list
+    #[serde(default, skip_serializing)]
+    best_30_words: Option<BestScore>,
+    /// Finished tests, oldest first
+    #[serde(default)]
+    pub history: Vec<TestResult>,
+    /// Best result of each daily challenge taken, oldest first
+    #[serde(default)]
+    pub daily: Vec<DailyResult>,
 }
 
 impl Profile {
     /// Create a new empty profile
     pub fn new() -> Self {
         Self {
+            best_scores: BTreeMap::new(),
             best_30_seconds: None,
             best_30_words: None,
+            history: Vec::new(),
+            daily: Vec::new(),
         }
     }
 
-    /// Update profile with a new score
-    /// Returns true if the score was a new personal best
-    pub fn update_score(&mut self, mode: &crate::test::TestMode, score: BestScore) -> bool {
-        match mode {
-            crate::test::TestMode::Time(30) => {
-                if let Some(current_best) = &self.best_30_seconds {
-                    if score.is_better_than(current_best) {
-                        self.best_30_seconds = Some(score);
-                        true
-                    } else {
-                        false
-                    }
-                } else {
-                    self.best_30_seconds = Some(score);
-                    true
-                }
+    /// Record a daily challenge result, keeping the best one of each day
+    /// Returns true if it is the best result of that day so far
+    pub fn record_daily(&mut self, daily: DailyResult) -> bool {
+        match self.daily.iter_mut().find(|existing| existing.date == daily.date) {
+            Some(existing) if daily.result.wpm > existing.result.wpm => *existing = daily,
+            Some(_) => return false,
+            None => {
+                self.daily.push(daily);
+                self.daily.sort_by(|a, b| a.date.cmp(&b.date));
+            }
+        }
+        true
+    }
+
+    /// Get the result of the daily challenge of a given day
+    pub fn daily_result(&self, date: NaiveDate) -> Option<&DailyResult> {
+        self.daily.iter().find(|daily| daily.day() == Some(date))
+    }
+
+    /// Count the days in a row with a daily challenge done, up to today
+    /// A streak isn't broken before the end of today, so it may end yesterday.
+    pub fn daily_streak(&self, today: NaiveDate) -> u32 {
+        let mut day = if self.daily_result(today).is_some() {
+            today
+        } else {
+            match today.pred_opt() {
+                Some(yesterday) => yesterday,
+                None => return 0,
+            }
+        };
+
+        let mut streak = 0;
+        while self.daily_result(day).is_some() {
+            streak += 1;
+            match day.pred_opt() {
+                Some(previous) => day = previous,
+                None => break,
+            }
+        }
+        streak
+    }
+
+    /// Add a finished test to the history
+    pub fn add_result(&mut self, result: TestResult) {
+        self.history.push(result);
+        if self.history.len() > MAX_HISTORY {
+            let excess = self.history.len() - MAX_HISTORY;
+            self.history.drain(..excess);
+        }
+    }
+
+    /// Move the best scores of an older profile to `best_scores`
+    /// They were typed on the list that is now the default one. Called when a profile is loaded.
+    pub fn migrate_best_scores(&mut self) {
+        let legacy = [
+            (TestMode::default_time(), self.best_30_seconds.take()),
+            (TestMode::default_words(), self.best_30_words.take()),
+        ];
+        for (mode, score) in legacy {
+            if let Some(score) = score {
+                self.best_scores.entry(best_score_key(&mode, DEFAULT_WORD_LIST)).or_insert(score);
             }
-            crate::test::TestMode::Words(30) => {
-                if let Some(current_best) = &self.best_30_words {
-                    if score.is_better_than(current_best) {
-                        self.best_30_words = Some(score);
-                        true
-                    } else {
-                        false
-                    }
-                } else {
-                    self.best_30_words = Some(score);
-                    true
-                }
+        }
+    }
+
+    /// Get the best score of a mode on a word list
+    pub fn best_score(&self, mode: &TestMode, word_list: &str) -> Option<&BestScore> {
+        self.best_scores.get(&best_score_key(mode, word_list))
+    }
+
+    /// Update profile with a new score on a word list
+    /// Returns true if the score was a new personal best
+    pub fn update_score(&mut self, mode: &TestMode, word_list: &str, score: BestScore) -> bool {
+        // Don't track custom modes
+        if *mode != TestMode::default_time() && *mode != TestMode::default_words() {
+            return false;
+        }
+
+        let key = best_score_key(mode, word_list);
+        match self.best_scores.get(&key) {
+            Some(current_best) if !score.is_better_than(current_best) => false,
+            _ => {
+                self.best_scores.insert(key, score);
+                true
             }
-            _ => false, // Don't track custom modes
         }
     }
 }
@@ -94,3 +241,59 @@ impl Default for Profile {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_without_history_loads() {
+        let profile: Profile = serde_json::from_str(r#"{"best_30_seconds": null, "best_30_words": null}"#).unwrap();
+        assert!(profile.history.is_empty());
+    }
+
+    #[test]
+    fn test_best_scores_per_word_list() {
+        let json = r#"{"best_30_seconds": {"wpm": 60.0, "cpm": 300.0, "accuracy": 95.0, "timestamp": 1}, "best_30_words": null}"#;
+        let mut profile: Profile = serde_json::from_str(json).unwrap();
+        profile.migrate_best_scores();
+        let time = TestMode::default_time();
+        assert_eq!(profile.best_score(&time, DEFAULT_WORD_LIST).unwrap().wpm, 60.0);
+        assert!(!serde_json::to_string(&profile).unwrap().contains("best_30_seconds"));
+
+        // Each list has its own best score
+        assert!(profile.update_score(&time, "english", BestScore::new(50.0, 250.0, 99.0)));
+        assert!(!profile.update_score(&time, DEFAULT_WORD_LIST, BestScore::new(50.0, 250.0, 99.0)));
+        assert!(!profile.update_score(&TestMode::Time(15), "english", BestScore::new(90.0, 450.0, 99.0)));
+        assert_eq!(profile.best_scores.keys().collect::<Vec<_>>(), ["time_30:english", "time_30:polish"]);
+    }
+
+    #[test]
+    fn test_daily_keeps_best_and_counts_streak() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let daily = |d: u32, wpm: f64| DailyResult::new(day(d), TestResult::new(TestMode::default_time(), wpm, 0.0, 100.0, None, None));
+        let mut profile = Profile::new();
+
+        assert!(profile.record_daily(daily(16, 50.0)));
+        assert!(profile.record_daily(daily(15, 40.0)));
+        assert!(profile.record_daily(daily(17, 60.0)));
+        assert!(!profile.record_daily(daily(17, 55.0)));
+        assert_eq!(profile.daily_result(day(17)).unwrap().result.wpm, 60.0);
+        assert_eq!(profile.daily[0].date, "2026-10-15");
+
+        // Today isn't done yet, the streak still counts up to yesterday
+        assert_eq!(profile.daily_streak(day(18)), 3);
+        assert_eq!(profile.daily_streak(day(17)), 3);
+        assert_eq!(profile.daily_streak(day(19)), 0);
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut profile = Profile::new();
+        for i in 0..MAX_HISTORY + 5 {
+            profile.add_result(TestResult::new(TestMode::default_time(), i as f64, 0.0, 100.0, None, None));
+        }
+        assert_eq!(profile.history.len(), MAX_HISTORY);
+        assert_eq!(profile.history[0].wpm, 5.0);
+    }
+}
@@ -1,13 +1,47 @@
 use anyhow::{Context, Result};
+use std::ffi::OsString;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use super::models::Profile;
 
-/// Get the path to the profile file
-/// Uses ~/.config/termotype/profile.json on Linux/Mac
-/// Uses %APPDATA%/termotype/profile.json on Windows
-fn get_profile_path() -> Result<PathBuf> {
+/// Directory given with --profile-dir, used instead of the default one
+static CONFIG_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Use another directory for the profile and config files
+/// Must be called before anything is loaded; later calls are ignored.
+pub fn set_config_dir(dir: PathBuf) {
+    let _ = CONFIG_DIR_OVERRIDE.set(dir);
+}
+
+/// Get the termotype configuration directory, without creating it
+/// Uses ~/.config/termotype on Linux/Mac
+/// Uses %APPDATA%/termotype on Windows
+/// Uses the directory from `set_config_dir` if one was set
+pub fn config_dir() -> Result<PathBuf> {
+    Ok(match CONFIG_DIR_OVERRIDE.get() {
+        Some(dir) => dir.clone(),
+        None => default_config_dir()?.join("termotype"),
+    })
+}
+
+/// Get the termotype configuration directory, creating it if needed
+/// Only for writing: reading a file that isn't there shouldn't leave an empty directory behind.
+pub fn get_config_dir() -> Result<PathBuf> {
+    let termotype_dir = config_dir()?;
+
+    // Create directory if it doesn't exist
+    if !termotype_dir.exists() {
+        fs::create_dir_all(&termotype_dir)
+            .context("Failed to create termotype config directory")?;
+    }
+
+    Ok(termotype_dir)
+}
+
+/// Get the platform's directory for application config
+fn default_config_dir() -> Result<PathBuf> {
     let config_dir = if cfg!(target_os = "windows") {
         // Windows: use APPDATA
         std::env::var("APPDATA")
@@ -20,19 +54,53 @@ fn get_profile_path() -> Result<PathBuf> {
         PathBuf::from(home).join(".config")
     };
 
-    let termotype_dir = config_dir.join("termotype");
+    Ok(config_dir)
+}
 
-    // Create directory if it doesn't exist
-    if !termotype_dir.exists() {
-        fs::create_dir_all(&termotype_dir)
-            .context("Failed to create termotype config directory")?;
+/// Get the directories searched for the user's word lists, the first one first
+/// Uses $XDG_DATA_HOME/termotype/wordlists (~/.local/share by default) and the same under each of
+/// $XDG_DATA_DIRS (/usr/local/share and /usr/share by default) on Linux/Mac
+/// Uses %APPDATA%/termotype/wordlists on Windows
+/// The directories aren't created; lists are found only in the ones that exist.
+pub fn get_wordlists_dirs() -> Vec<PathBuf> {
+    if cfg!(target_os = "windows") {
+        return std::env::var_os("APPDATA")
+            .map(|dir| PathBuf::from(dir).join("termotype").join("wordlists"))
+            .into_iter()
+            .collect();
     }
 
-    Ok(termotype_dir.join("profile.json"))
+    xdg_wordlists_dirs(
+        std::env::var_os("XDG_DATA_HOME"),
+        std::env::var_os("XDG_DATA_DIRS"),
+        std::env::var_os("HOME"),
+    )
+}
+
+/// Get the word list directories from the XDG variables
+/// Unset or empty variables use the defaults, and relative paths are ignored (as the spec says).
+fn xdg_wordlists_dirs(data_home: Option<OsString>, data_dirs: Option<OsString>, home: Option<OsString>) -> Vec<PathBuf> {
+    let data_home = data_home
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| home.map(|home| PathBuf::from(home).join(".local").join("share")));
+    let data_dirs = data_dirs.filter(|dirs| !dirs.is_empty()).unwrap_or_else(|| "/usr/local/share:/usr/share".into());
+
+    data_home
+        .into_iter()
+        .chain(std::env::split_paths(&data_dirs).filter(|dir| dir.is_absolute()))
+        .map(|dir| dir.join("termotype").join("wordlists"))
+        .collect()
+}
+
+/// Get the path to the profile file
+fn get_profile_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("profile.json"))
 }
 
 /// Save profile to disk
 pub fn save_profile(profile: &Profile) -> Result<()> {
+    get_config_dir()?;
     let path = get_profile_path()?;
 
     let json = serde_json::to_string_pretty(profile)
@@ -57,8 +125,9 @@ pub fn load_profile() -> Result<Profile> {
     let content = fs::read_to_string(&path)
         .with_context(|| format!("Failed to read profile from {:?}", path))?;
 
-    let profile: Profile = serde_json::from_str(&content)
+    let mut profile: Profile = serde_json::from_str(&content)
         .context("Failed to parse profile JSON")?;
+    profile.migrate_best_scores();
 
     Ok(profile)
 }
@@ -79,4 +148,25 @@ mod tests {
         let path = get_profile_path();
         assert!(path.is_ok());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_xdg_wordlists_dirs() {
+        let dirs = xdg_wordlists_dirs(None, None, Some("/home/user".into()));
+        assert_eq!(
+            dirs,
+            [
+                PathBuf::from("/home/user/.local/share/termotype/wordlists"),
+                PathBuf::from("/usr/local/share/termotype/wordlists"),
+                PathBuf::from("/usr/share/termotype/wordlists"),
+            ]
+        );
+
+        let dirs = xdg_wordlists_dirs(Some("/data".into()), Some("relative:/opt/share".into()), None);
+        assert_eq!(
+            dirs,
+            [PathBuf::from("/data/termotype/wordlists"), PathBuf::from("/opt/share/termotype/wordlists")]
+        );
+        assert_eq!(xdg_wordlists_dirs(Some("relative".into()), Some("".into()), None).len(), 2);
+    }
 }
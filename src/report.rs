@@ -0,0 +1,359 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::profile::{best_score_key, DailyResult, Profile, TestResult};
+use crate::test::{TestMetrics, TestMode};
+
+/// How a run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    /// The test was typed to the end
+    Finished,
+    /// The program was quit before the test ended
+    Aborted,
+}
+
+/// Character counts of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CharCounts {
+    /// Characters typed correctly in completed words
+    pub correct: usize,
+    /// Characters typed incorrectly in completed words
+    pub incorrect: usize,
+    /// Every key press that typed a character (including corrected ones)
+    pub typed: usize,
+}
+
+/// Result of a run, printed with --print-result or --json
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSummary {
+    /// How the run ended
+    pub status: RunStatus,
+    /// Mode the test was run in
+    pub mode: TestMode,
+    /// Where the text came from: "word_list", "text", "quote", "daily" or "challenge"
+    pub source: &'static str,
+    /// Name of the word list (random words only), or the path of a file that isn't in the list registry
+    pub word_list: Option<String>,
+    /// Seed of the words (None for a fixed text)
+    pub seed: Option<u64>,
+    /// Code to share the test (random words only)
+    pub challenge: Option<String>,
+    /// Time spent typing, in seconds
+    pub duration_seconds: f64,
+    /// Speed and accuracy
+    #[serde(flatten)]
+    pub metrics: TestMetrics,
+    /// Character counts
+    pub chars: CharCounts,
+}
+
+impl RunSummary {
+    /// Format the result as a single line
+    pub fn line(&self) -> String {
+        let status = match self.status {
+            RunStatus::Finished => "finished",
+            RunStatus::Aborted => "aborted",
+        };
+        let mut line = format!(
+            "{}: {:.0} wpm, {:.0} cpm, {:.1}% accuracy, {}, {:.1}s, {}/{} chars",
+            status,
+            self.metrics.wpm,
+            self.metrics.cpm,
+            self.metrics.accuracy,
+            mode_label(&self.mode),
+            self.duration_seconds,
+            self.chars.correct,
+            self.chars.correct + self.chars.incorrect,
+        );
+        if let Some(seed) = self.seed {
+            line.push_str(&format!(", seed {}", seed));
+        }
+        if let Some(code) = &self.challenge {
+            line.push_str(&format!(", challenge {}", code));
+        }
+        line
+    }
+
+    /// Format the result as JSON
+    pub fn json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Summary of the results in one mode
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModeStats {
+    /// Mode the results were typed in
+    pub mode: TestMode,
+    /// Number of finished tests in the history
+    pub tests: usize,
+    /// Best WPM (from the history or the saved best score)
+    pub best_wpm: f64,
+    /// Average WPM over the history
+    pub average_wpm: Option<f64>,
+    /// Average accuracy over the history
+    pub average_accuracy: Option<f64>,
+}
+
+/// Get a short label for a mode ("time 30s", "words 50")
+pub fn mode_label(mode: &TestMode) -> String {
+    match mode {
+        TestMode::Time(seconds) => format!("time {}s", seconds),
+        TestMode::Words(count) => format!("words {}", count),
+    }
+}
+
+/// Format a Unix timestamp as a date and time (UTC)
+pub fn format_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Collect the stats of every mode with a result, time modes first
+pub fn mode_stats(profile: &Profile) -> Vec<ModeStats> {
+    // Best saved score of a mode, on any word list
+    let saved_best = |mode: &TestMode| {
+        let prefix = best_score_key(mode, "");
+        profile
+            .best_scores
+            .iter()
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, best)| best.wpm)
+            .reduce(f64::max)
+    };
+
+    let mut modes: Vec<TestMode> = profile.history.iter().map(|result| result.mode).collect();
+    let tracked = [TestMode::default_time(), TestMode::default_words()];
+    modes.extend(tracked.into_iter().filter(|mode| saved_best(mode).is_some()));
+    modes.sort_by_key(|mode| match mode {
+        TestMode::Time(seconds) => (0, *seconds),
+        TestMode::Words(count) => (1, *count),
+    });
+    modes.dedup();
+
+    modes
+        .into_iter()
+        .map(|mode| {
+            let results: Vec<&TestResult> = profile.history.iter().filter(|result| result.mode == mode).collect();
+            let best_wpm = results.iter().map(|result| result.wpm).chain(saved_best(&mode)).fold(0.0, f64::max);
+            let average = |value: fn(&TestResult) -> f64| {
+                (!results.is_empty()).then(|| results.iter().map(|result| value(result)).sum::<f64>() / results.len() as f64)
+            };
+
+            ModeStats {
+                mode,
+                tests: results.len(),
+                best_wpm,
+                average_wpm: average(|result| result.wpm),
+                average_accuracy: average(|result| result.accuracy),
+            }
+        })
+        .collect()
+}
+
+/// Get the most recent results, newest first
+pub fn recent_results(profile: &Profile, limit: usize) -> Vec<&TestResult> {
+    profile.history.iter().rev().take(limit).collect()
+}
+
+/// Get the most recent daily challenge results, newest first
+pub fn recent_dailies(profile: &Profile, limit: usize) -> Vec<&DailyResult> {
+    profile.daily.iter().rev().take(limit).collect()
+}
+
+/// Format the stats as a table
+/// `today` is the current day (UTC), for the daily streak.
+pub fn stats_table(profile: &Profile, today: NaiveDate) -> String {
+    let stats = mode_stats(profile);
+    if stats.is_empty() && profile.daily.is_empty() {
+        return "No results yet - finish a test first.".to_string();
+    }
+
+    let mut out = format!("{:<12}{:>7}{:>10}{:>9}{:>9}\n", "Mode", "Tests", "Best WPM", "Avg WPM", "Avg Acc");
+    for row in &stats {
+        out.push_str(&format!(
+            "{:<12}{:>7}{:>10.0}{:>9}{:>9}\n",
+            mode_label(&row.mode),
+            row.tests,
+            row.best_wpm,
+            row.average_wpm.map_or("-".to_string(), |wpm| format!("{:.0}", wpm)),
+            row.average_accuracy.map_or("-".to_string(), |accuracy| format!("{:.1}%", accuracy)),
+        ));
+    }
+    out.push_str(&format!("\nTotal tests: {}", profile.history.len()));
+    out.push_str(&format!(
+        "\nDaily challenges: {} (streak: {})",
+        profile.daily.len(),
+        profile.daily_streak(today)
+    ));
+    out
+}
+
+/// Format the stats as JSON
+pub fn stats_json(profile: &Profile, today: NaiveDate) -> String {
+    let report = serde_json::json!({
+        "total_tests": profile.history.len(),
+        "modes": mode_stats(profile),
+        "daily_challenges": profile.daily.len(),
+        "daily_streak": profile.daily_streak(today),
+    });
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+/// Format the recent results as a table
+pub fn history_table(profile: &Profile, limit: usize) -> String {
+    let results = recent_results(profile, limit);
+    if results.is_empty() {
+        return "No results yet - finish a test first.".to_string();
+    }
+
+    let mut out = format!(
+        "{:<18}{:<12}{:>6}{:>6}{:>9}  {:<12}{}\n",
+        "Date (UTC)", "Mode", "WPM", "CPM", "Acc", "List", "Seed"
+    );
+    for result in results {
+        out.push_str(&format!(
+            "{:<18}{:<12}{:>6.0}{:>6.0}{:>8.1}%  {:<12}{}\n",
+            format_timestamp(result.timestamp),
+            mode_label(&result.mode),
+            result.wpm,
+            result.cpm,
+            result.accuracy,
+            result.word_list.as_deref().unwrap_or("-"),
+            result.seed.map_or("-".to_string(), |seed| seed.to_string()),
+        ));
+    }
+    out.pop();
+    out
+}
+
+/// Format the recent results as JSON
+pub fn history_json(profile: &Profile, limit: usize) -> String {
+    serde_json::to_string_pretty(&recent_results(profile, limit)).unwrap_or_default()
+}
+
+/// Format the recent daily challenge results as a table
+pub fn daily_table(profile: &Profile, limit: usize) -> String {
+    let dailies = recent_dailies(profile, limit);
+    if dailies.is_empty() {
+        return "No daily challenges yet - take one with --daily.".to_string();
+    }
+
+    let mut out = format!("{:<12}{:<12}{:>6}{:>6}{:>9}\n", "Day", "Mode", "WPM", "CPM", "Acc");
+    for daily in dailies {
+        out.push_str(&format!(
+            "{:<12}{:<12}{:>6.0}{:>6.0}{:>8.1}%\n",
+            daily.date,
+            mode_label(&daily.result.mode),
+            daily.result.wpm,
+            daily.result.cpm,
+            daily.result.accuracy,
+        ));
+    }
+    out.pop();
+    out
+}
+
+/// Format the recent daily challenge results as JSON
+pub fn daily_json(profile: &Profile, limit: usize) -> String {
+    serde_json::to_string_pretty(&recent_dailies(profile, limit)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::BestScore;
+
+    fn profile() -> Profile {
+        let mut profile = Profile::new();
+        profile.update_score(&TestMode::Words(30), "english", BestScore::new(90.0, 450.0, 99.0));
+        for (mode, wpm, accuracy) in [
+            (TestMode::Words(30), 60.0, 95.0),
+            (TestMode::Time(60), 70.0, 97.0),
+            (TestMode::Words(30), 80.0, 99.0),
+        ] {
+            profile.add_result(TestResult::new(mode, wpm, wpm * 5.0, accuracy, Some(wpm as u64), Some("polish".into())));
+        }
+        profile
+    }
+
+    #[test]
+    fn test_mode_stats() {
+        let stats = mode_stats(&profile());
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].mode, TestMode::Time(60));
+        assert_eq!(stats[1].tests, 2);
+        assert_eq!(stats[1].best_wpm, 90.0);
+        assert_eq!(stats[1].average_wpm, Some(70.0));
+        assert_eq!(stats[1].average_accuracy, Some(97.0));
+    }
+
+    #[test]
+    fn test_history_is_newest_first() {
+        let profile = profile();
+        let recent = recent_results(&profile, 2);
+        assert_eq!(recent.iter().map(|result| result.wpm).collect::<Vec<_>>(), vec![80.0, 70.0]);
+
+        let json: serde_json::Value = serde_json::from_str(&history_json(&profile, 1)).unwrap();
+        assert_eq!(json[0]["wpm"], 80.0);
+        assert_eq!(json[0]["mode"]["words"], 30);
+        assert_eq!(json[0]["seed"], 80);
+        assert_eq!(json[0]["word_list"], "polish");
+        assert!(history_table(&profile, 1).lines().nth(1).unwrap().ends_with("  80"));
+    }
+
+    #[test]
+    fn test_dailies_are_newest_first() {
+        let mut profile = profile();
+        assert!(daily_table(&profile, 5).starts_with("No daily challenges yet"));
+
+        for (day, wpm) in [(16, 50.0), (17, 60.0)] {
+            let date = NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+            let result = TestResult::new(TestMode::Words(25), wpm, wpm * 5.0, 98.0, None, None);
+            profile.record_daily(DailyResult::new(date, result));
+        }
+        assert_eq!(recent_dailies(&profile, 1)[0].date, "2026-10-17");
+
+        let table = daily_table(&profile, 5);
+        assert_eq!(table.lines().count(), 3);
+        assert!(table.lines().nth(1).unwrap().starts_with("2026-10-17  words 25        60"));
+        let json: serde_json::Value = serde_json::from_str(&daily_json(&profile, 5)).unwrap();
+        assert_eq!(json[1]["date"], "2026-10-16");
+        assert_eq!(json[1]["wpm"], 50.0);
+    }
+
+    #[test]
+    fn test_run_summary() {
+        let summary = RunSummary {
+            status: RunStatus::Finished,
+            mode: TestMode::Words(10),
+            source: "word_list",
+            word_list: Some("words.json".to_string()),
+            seed: Some(42),
+            challenge: Some("tt1:w10:00c0ffee:16:words.json".to_string()),
+            duration_seconds: 12.0,
+            metrics: TestMetrics::calculate(50, 2, 12.0),
+            chars: CharCounts { correct: 50, incorrect: 2, typed: 55 },
+        };
+        assert_eq!(
+            summary.line(),
+            "finished: 50 wpm, 250 cpm, 96.2% accuracy, words 10, 12.0s, 50/52 chars, seed 42, \
+             challenge tt1:w10:00c0ffee:16:words.json"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&summary.json()).unwrap();
+        assert_eq!(json["status"], "finished");
+        assert_eq!(json["wpm"], 50.0);
+        assert_eq!(json["chars"]["typed"], 55);
+    }
+
+    #[test]
+    fn test_empty_profile() {
+        let profile = Profile::new();
+        assert!(mode_stats(&profile).is_empty());
+        assert!(history_table(&profile, 5).starts_with("No results"));
+    }
+}
@@ -0,0 +1,395 @@
+use serde::{Deserialize, Serialize};
+
+use crate::app::{App, TextSource};
+use crate::i18n::Messages;
+use crate::test::{Challenge, TestMode, MAX_SECONDS, MAX_WORDS};
+
+/// Step used when changing the test length with left/right
+const LENGTH_STEP: u32 = 5;
+
+/// What the restart action (Enter by default) does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartMode {
+    /// Start a new test with new random words
+    #[default]
+    NewWords,
+    /// Retry the same words
+    SameWords,
+}
+
+impl RestartMode {
+    /// Get the display name of the restart mode
+    pub fn name(&self, msg: &Messages) -> &'static str {
+        match self {
+            RestartMode::NewWords => msg.restart_new_words,
+            RestartMode::SameWords => msg.restart_same_words,
+        }
+    }
+
+    /// Get the other restart mode
+    pub fn next(&self) -> RestartMode {
+        match self {
+            RestartMode::NewWords => RestartMode::SameWords,
+            RestartMode::SameWords => RestartMode::NewWords,
+        }
+    }
+}
+
+/// Group of settings shown under one heading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Test,
+    Accessibility,
+    Caret,
+    Interface,
+}
+
+impl Category {
+    /// Get the display name of the category
+    pub fn name(&self, msg: &Messages) -> &'static str {
+        match self {
+            Category::Test => msg.test_mode,
+            Category::Accessibility => msg.accessibility,
+            Category::Caret => msg.caret,
+            Category::Interface => msg.interface,
+        }
+    }
+}
+
+/// How a setting is edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    /// One of a fixed set of values, cycled with left/right or Enter
+    Choice,
+    /// On/off value, flipped with left/right or Enter
+    Toggle,
+    /// Number, stepped with left/right or typed after Enter
+    Number,
+    /// Name from a list, cycled with left/right or typed after Enter
+    Name,
+    /// Free text, typed after Enter
+    Text,
+}
+
+/// A single editable setting in the Options tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    Mode,
+    Length,
+    WordList,
+    Seed,
+    /// Challenge code of a shared test
+    Challenge,
+    RestartMode,
+    ErrorStyle,
+    ShowTypedChars,
+    CaretStyle,
+    CaretBlink,
+    FocusMode,
+    FocusTimer,
+    Theme,
+    Language,
+}
+
+impl Setting {
+    /// Get all settings in display order
+    pub fn all() -> Vec<Setting> {
+        vec![
+            Setting::Mode,
+            Setting::Length,
+            Setting::WordList,
+            Setting::Seed,
+            Setting::Challenge,
+            Setting::RestartMode,
+            Setting::ErrorStyle,
+            Setting::ShowTypedChars,
+            Setting::CaretStyle,
+            Setting::CaretBlink,
+            Setting::FocusMode,
+            Setting::FocusTimer,
+            Setting::Theme,
+            Setting::Language,
+        ]
+    }
+
+    /// Get the category the setting is listed under
+    pub fn category(&self) -> Category {
+        match self {
+            Setting::Mode
+            | Setting::Length
+            | Setting::WordList
+            | Setting::Seed
+            | Setting::Challenge
+            | Setting::RestartMode => Category::Test,
+            Setting::ErrorStyle | Setting::ShowTypedChars => Category::Accessibility,
+            Setting::CaretStyle | Setting::CaretBlink => Category::Caret,
+            Setting::FocusMode | Setting::FocusTimer | Setting::Theme | Setting::Language => Category::Interface,
+        }
+    }
+
+    /// Get how the setting is edited
+    pub fn kind(&self) -> SettingKind {
+        match self {
+            Setting::Mode
+            | Setting::RestartMode
+            | Setting::ErrorStyle
+            | Setting::CaretStyle
+            | Setting::Theme
+            | Setting::Language => SettingKind::Choice,
+            Setting::ShowTypedChars | Setting::CaretBlink | Setting::FocusMode | Setting::FocusTimer => {
+                SettingKind::Toggle
+            }
+            Setting::Length => SettingKind::Number,
+            Setting::WordList => SettingKind::Name,
+            Setting::Seed | Setting::Challenge => SettingKind::Text,
+        }
+    }
+
+    /// Get the display label of the setting
+    pub fn label(&self, app: &App) -> &'static str {
+        let msg = app.messages();
+        match self {
+            Setting::Mode => msg.mode,
+            Setting::Length => match app.test_mode {
+                TestMode::Time(_) => msg.duration_seconds,
+                TestMode::Words(_) => msg.word_count,
+            },
+            Setting::WordList => msg.word_list,
+            Setting::Seed => msg.seed,
+            Setting::Challenge => msg.challenge_code,
+            Setting::RestartMode => msg.restart_with,
+            Setting::ErrorStyle => msg.error_indication,
+            Setting::ShowTypedChars => msg.show_typed_chars,
+            Setting::CaretStyle => msg.caret_style,
+            Setting::CaretBlink => msg.caret_blink,
+            Setting::FocusMode => msg.focus_mode,
+            Setting::FocusTimer => msg.focus_timer,
+            Setting::Theme => msg.theme,
+            Setting::Language => msg.language,
+        }
+    }
+
+    /// Get the current value of the setting for display
+    pub fn value(&self, app: &App) -> String {
+        let msg = app.messages();
+        match self {
+            Setting::Mode => match app.test_mode {
+                TestMode::Time(_) => msg.mode_time.to_string(),
+                TestMode::Words(_) => msg.mode_words.to_string(),
+            },
+            Setting::Length => match app.test_mode {
+                TestMode::Time(n) | TestMode::Words(n) => n.to_string(),
+            },
+            Setting::WordList => app.word_list.clone(),
+            Setting::Seed => app.seed.map_or(msg.seed_random.to_string(), |seed| seed.to_string()),
+            Setting::Challenge => match &app.text_source {
+                TextSource::Challenge(challenge, _) => challenge.code(),
+                _ => msg.challenge_none.to_string(),
+            },
+            Setting::RestartMode => app.restart_mode.name(msg).to_string(),
+            Setting::ErrorStyle => app.error_style.name(msg).to_string(),
+            Setting::ShowTypedChars => msg.on_off(app.show_typed_chars).to_string(),
+            Setting::CaretStyle => app.caret_style.name(msg).to_string(),
+            Setting::CaretBlink => msg.on_off(app.caret_blink).to_string(),
+            Setting::FocusMode => msg.on_off(app.focus_mode).to_string(),
+            Setting::FocusTimer => msg.on_off(app.focus_timer).to_string(),
+            Setting::Theme => app.theme.name(msg).to_string(),
+            Setting::Language => app.language.name().to_string(),
+        }
+    }
+
+    /// Change the setting by one step (left/right)
+    pub fn adjust(&self, app: &mut App, forward: bool) {
+        match self {
+            Setting::Mode => {
+                let mode = match app.test_mode {
+                    TestMode::Time(_) => TestMode::default_words(),
+                    TestMode::Words(_) => TestMode::default_time(),
+                };
+                app.set_test_mode(mode);
+            }
+            Setting::Length => {
+                let mode = match app.test_mode {
+                    TestMode::Time(n) => TestMode::Time(step(n, forward, MAX_SECONDS)),
+                    TestMode::Words(n) => TestMode::Words(step(n, forward, MAX_WORDS)),
+                };
+                app.set_test_mode(mode);
+            }
+            Setting::WordList => {
+                let name = app.word_lists.cycle(&app.word_list, forward).to_string();
+                app.set_word_list(name);
+            }
+            Setting::Seed | Setting::Challenge => {}
+            Setting::RestartMode => app.restart_mode = app.restart_mode.next(),
+            Setting::ErrorStyle => {
+                app.error_style = if forward { app.error_style.next() } else { app.error_style.prev() };
+            }
+            Setting::ShowTypedChars => app.show_typed_chars = !app.show_typed_chars,
+            Setting::CaretStyle => {
+                app.caret_style = if forward { app.caret_style.next() } else { app.caret_style.prev() };
+            }
+            Setting::CaretBlink => app.caret_blink = !app.caret_blink,
+            Setting::FocusMode => app.focus_mode = !app.focus_mode,
+            Setting::FocusTimer => app.focus_timer = !app.focus_timer,
+            Setting::Theme => app.theme = if forward { app.theme.next() } else { app.theme.prev() },
+            Setting::Language => app.language = app.language.next(),
+        }
+    }
+
+    /// Apply a typed value to the setting
+    /// Returns a user-facing error message if the value is invalid
+    pub fn apply_input(&self, app: &mut App, input: &str) -> Result<(), String> {
+        let msg = app.messages();
+        match self {
+            Setting::Length => {
+                let max = match app.test_mode {
+                    TestMode::Time(_) => MAX_SECONDS,
+                    TestMode::Words(_) => MAX_WORDS,
+                };
+                let value = input
+                    .trim()
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| (1..=max).contains(n))
+                    .ok_or_else(|| format!("{} (1-{})", msg.invalid_number, max))?;
+
+                let mode = match app.test_mode {
+                    TestMode::Time(_) => TestMode::Time(value),
+                    TestMode::Words(_) => TestMode::Words(value),
+                };
+                app.set_test_mode(mode);
+                Ok(())
+            }
+            Setting::WordList => {
+                // Any file can be typed, not just the lists offered by left/right
+                let name = input.trim();
+                app.word_lists.load(name).map_err(|err| format!("{}: {:#}", msg.word_list_load_failed, err))?;
+                app.set_word_list(name.to_string());
+                Ok(())
+            }
+            Setting::Seed => {
+                let input = input.trim();
+                app.seed = if input.is_empty() || input == msg.seed_random {
+                    None
+                } else {
+                    Some(input.parse().map_err(|_| msg.invalid_seed.to_string())?)
+                };
+                app.leave_challenge();
+                app.init_test();
+                Ok(())
+            }
+            Setting::Challenge => {
+                let input = input.trim();
+                if input.is_empty() || input == msg.challenge_none {
+                    app.leave_challenge();
+                } else {
+                    let challenge = Challenge::parse(input).map_err(|err| format!("{}: {}", msg.invalid_challenge, err))?;
+                    app.text_source = app.challenge_source(&challenge)?;
+                }
+                app.init_test();
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Step a number up or down, keeping it within 1..=max
+fn step(value: u32, forward: bool, max: u32) -> u32 {
+    if forward {
+        (value + LENGTH_STEP - value % LENGTH_STEP).min(max)
+    } else if value > LENGTH_STEP {
+        (value - 1) / LENGTH_STEP * LENGTH_STEP
+    } else {
+        1
+    }
+}
+
+/// State of the settings menu in the Options tab
+#[derive(Debug, Clone, Default)]
+pub struct SettingsMenu {
+    /// Index of the selected setting in `Setting::all()`
+    pub selected: usize,
+    /// Text being typed for a number or text setting (None if not editing)
+    pub input: Option<String>,
+    /// Validation error from the last edit
+    pub error: Option<String>,
+}
+
+impl SettingsMenu {
+    /// Get the selected setting
+    pub fn selected_setting(&self) -> Setting {
+        let settings = Setting::all();
+        settings[self.selected.min(settings.len() - 1)]
+    }
+
+    /// Select a setting by index
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(Setting::all().len() - 1);
+        self.input = None;
+        self.error = None;
+    }
+
+    /// Select the next setting
+    pub fn select_next(&mut self) {
+        self.select(self.selected + 1);
+    }
+
+    /// Select the previous setting
+    pub fn select_prev(&mut self) {
+        self.select(self.selected.saturating_sub(1));
+    }
+
+    /// Check if a value is being typed
+    pub fn is_editing(&self) -> bool {
+        self.input.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::profile::Profile;
+
+    #[test]
+    fn test_step() {
+        assert_eq!(step(30, true, 100), 35);
+        assert_eq!(step(32, true, 100), 35);
+        assert_eq!(step(30, false, 100), 25);
+        assert_eq!(step(32, false, 100), 30);
+        assert_eq!(step(5, false, 100), 1);
+        assert_eq!(step(1, true, 100), 5);
+        assert_eq!(step(100, true, 100), 100);
+    }
+
+    #[test]
+    fn test_menu_selection_is_clamped() {
+        let mut menu = SettingsMenu::default();
+        menu.select_prev();
+        assert_eq!(menu.selected, 0);
+
+        menu.select(100);
+        assert_eq!(menu.selected_setting(), Setting::Language);
+    }
+
+    #[test]
+    fn test_word_list_path_can_be_typed() {
+        let path = std::env::temp_dir().join(format!("termotype-typed-list-{}.json", std::process::id()));
+        std::fs::write(&path, r#"["alpha", "beta"]"#).unwrap();
+        let path = path.to_string_lossy().into_owned();
+        let mut app = App::with_state(Config::default(), Vec::new(), Profile::new());
+        app.init_test();
+
+        let typed = Setting::WordList.apply_input(&mut app, &format!(" {} ", path));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(typed, Ok(()));
+        assert_eq!(app.word_list, path);
+        let words = &app.test_engine.as_ref().unwrap().words;
+        assert!(words.iter().all(|word| word == "alpha" || word == "beta"));
+
+        assert!(Setting::WordList.apply_input(&mut app, "/nonexistent/list.json").is_err());
+        assert_eq!(app.word_list, path);
+    }
+}
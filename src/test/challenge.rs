@@ -0,0 +1,183 @@
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+use super::words::{generate_word_sequence, seeded_rng, time_word_count};
+use super::{TestEngine, TestMode, MAX_SECONDS, MAX_WORDS};
+
+/// Version written at the start of every code; bumped when the format changes
+const VERSION: &str = "tt1";
+
+/// A test of random words that can be shared as a short code
+///
+/// The code is `tt1:<mode>:<list hash>:<seed>:<list name>`, e.g. `tt1:w50:580b034a:2kd7x0q1b:polish`:
+/// the mode is `t` (seconds) or `w` (words) and its length, the hash identifies the word list's content,
+/// the seed is in base 36 and the list name (the file name, as paths differ between machines) comes last,
+/// so it can contain any character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    /// Mode of the test
+    pub mode: TestMode,
+    /// Name of the word list (the file name for a list given by path)
+    pub word_list: String,
+    /// Hash of the word list's words, so a different list with the same name is noticed
+    pub list_hash: u32,
+    /// Seed of the words
+    pub seed: u64,
+}
+
+impl Challenge {
+    /// Describe a test of random words from `word_list` (a list name or a file path)
+    pub fn new(mode: TestMode, word_list: &str, words: &[String], seed: u64) -> Self {
+        let name = Path::new(word_list).file_name().map_or(word_list.into(), |name| name.to_string_lossy());
+        Self { mode, word_list: name.into_owned(), list_hash: word_list_hash(words), seed }
+    }
+
+    /// Get the shareable code
+    pub fn code(&self) -> String {
+        let mode = match self.mode {
+            TestMode::Time(seconds) => format!("t{}", seconds),
+            TestMode::Words(count) => format!("w{}", count),
+        };
+        format!("{}:{}:{:08x}:{}:{}", VERSION, mode, self.list_hash, to_base36(self.seed), self.word_list)
+    }
+
+    /// Parse a code made by `code()`
+    pub fn parse(code: &str) -> Result<Self> {
+        let mut parts = code.trim().splitn(5, ':');
+        let (Some(version), Some(mode), Some(hash), Some(seed), Some(word_list)) =
+            (parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            bail!("not a challenge code");
+        };
+
+        if version != VERSION {
+            bail!("unsupported version '{}'", version);
+        }
+        // Within the limits of the Options tab, so a code can't ask for billions of words
+        let length = |text: &str, max: u32| text.parse::<u32>().ok().filter(|n| (1..=max).contains(n));
+        let mode = match mode.split_at_checked(1) {
+            Some(("t", seconds)) => length(seconds, MAX_SECONDS).map(TestMode::Time),
+            Some(("w", count)) => length(count, MAX_WORDS).map(TestMode::Words),
+            _ => None,
+        }
+        .with_context(|| format!("invalid mode '{}'", mode))?;
+        let list_hash = u32::from_str_radix(hash, 16).with_context(|| format!("invalid word list hash '{}'", hash))?;
+        let seed = u64::from_str_radix(seed, 36).with_context(|| format!("invalid seed '{}'", seed))?;
+        if word_list.is_empty() {
+            bail!("missing word list name");
+        }
+
+        Ok(Self { mode, word_list: word_list.to_string(), list_hash, seed })
+    }
+
+    /// Check if `words` is the word list of the challenge
+    pub fn matches(&self, words: &[String]) -> bool {
+        word_list_hash(words) == self.list_hash
+    }
+
+    /// Set up the test: the same code and word list always give the same words in the same order
+    pub fn engine(&self, words: &[String]) -> TestEngine {
+        let count = match self.mode {
+            TestMode::Words(count) => count as usize,
+            TestMode::Time(seconds) => time_word_count(seconds),
+        };
+        let test_words = generate_word_sequence(count, words, &mut seeded_rng(self.seed));
+
+        let mut engine = TestEngine::new(self.mode, test_words);
+        engine.seed = Some(self.seed);
+        engine
+    }
+}
+
+/// Hash the words of a list (32-bit FNV-1a over the words, each followed by a newline)
+/// The hash is part of every code, so it must come out the same on the machine that reads a code
+/// as on the one that made it, whichever build of termotype each of them runs.
+pub fn word_list_hash(words: &[String]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in words.iter().flat_map(|word| word.bytes().chain([b'\n'])) {
+        hash = (hash ^ byte as u32).wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// Write a number in base 36 (digits and lowercase letters)
+fn to_base36(mut value: u64) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((value % 36) as u32, 36).unwrap_or('0'));
+        value /= 36;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words() -> Vec<String> {
+        ["alpha", "beta", "gamma", "delta"].iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_code_round_trip() {
+        let challenge = Challenge::new(TestMode::Time(60), "/home/user/lists/my:list.json", &words(), u64::MAX);
+        assert_eq!(challenge.word_list, "my:list.json");
+
+        let code = challenge.code();
+        assert_eq!(code, format!("tt1:t60:{:08x}:3w5e11264sgsf:my:list.json", challenge.list_hash));
+        assert_eq!(Challenge::parse(&code).unwrap(), challenge);
+        assert_eq!(Challenge::parse(&format!("  {}\n", code)).unwrap(), challenge);
+    }
+
+    #[test]
+    fn test_invalid_codes() {
+        assert!(Challenge::parse("hello").is_err());
+        assert!(Challenge::parse("tt2:w50:00000000:1:words.json").is_err());
+        assert!(Challenge::parse("tt1:x50:00000000:1:words.json").is_err());
+        assert!(Challenge::parse("tt1:w0:00000000:1:words.json").is_err());
+        assert!(Challenge::parse("tt1:w1001:00000000:1:words.json").is_err());
+        assert!(Challenge::parse("tt1:t3601:00000000:1:words.json").is_err());
+        assert!(Challenge::parse("tt1:w4000000000:d4b2e2d6:1:polish").is_err());
+        assert!(Challenge::parse("tt1:w1000:00000000:1:words.json").is_ok());
+        assert!(Challenge::parse("tt1:t3600:00000000:1:words.json").is_ok());
+        assert!(Challenge::parse("tt1:w50:nothex:1:words.json").is_err());
+        assert!(Challenge::parse("tt1:w50:00000000:!:words.json").is_err());
+        assert!(Challenge::parse("tt1:w50:00000000:1:").is_err());
+    }
+
+    #[test]
+    fn test_same_code_same_test() {
+        let code = Challenge::new(TestMode::Words(30), "words.json", &words(), 7).code();
+        let challenge = Challenge::parse(&code).unwrap();
+        assert!(challenge.matches(&words()));
+        assert!(!challenge.matches(&words()[1..]));
+
+        let first = challenge.engine(&words());
+        let second = challenge.engine(&words());
+        assert_eq!(first.mode, TestMode::Words(30));
+        assert_eq!(first.words.len(), 30);
+        assert_eq!(first.words, second.words);
+        assert_eq!(first.seed, Some(7));
+    }
+
+    #[test]
+    fn test_words_are_stable() {
+        // A shared code must give these words on every build, or its results can't be compared
+        let challenge = Challenge::new(TestMode::Words(8), "words.json", &words(), 7);
+        assert_eq!(
+            challenge.engine(&words()).words,
+            ["beta", "alpha", "alpha", "gamma", "delta", "beta", "alpha", "beta"]
+        );
+    }
+
+    #[test]
+    fn test_word_list_hash_is_stable() {
+        // Codes already shared carry these values, so they must keep matching their lists
+        assert_eq!(word_list_hash(&[]), 0x811c_9dc5);
+        assert_eq!(word_list_hash(&words()), 0xe9dc_d8a0);
+        assert_ne!(word_list_hash(&["ab".into(), "c".into()]), word_list_hash(&["a".into(), "bc".into()]));
+    }
+}
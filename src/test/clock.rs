@@ -0,0 +1,77 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Source of the current time for a test
+/// The engine reads every timestamp from its clock, so tests can control time.
+pub trait Clock: Send + Sync {
+    /// Get the current time
+    fn now(&self) -> Instant;
+}
+
+/// The real clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when told to
+/// Clones share the same time, so one can be given to an engine and the other advanced.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    /// Time the clock started at
+    origin: Instant,
+    /// Time passed since `origin` (in nanoseconds)
+    elapsed_nanos: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    /// Create a clock stopped at the current time
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            elapsed_nanos: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self.elapsed_nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    /// Get the time passed since the clock was created
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_is_shared() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+
+        clock.clone().advance(Duration::from_millis(120));
+        assert_eq!(clock.now() - start, Duration::from_millis(120));
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{Datelike, NaiveDate, Utc};
+
+use super::TestMode;
+
+/// Modes a daily challenge can have
+pub const DAILY_MODES: [TestMode; 5] = [
+    TestMode::Time(15),
+    TestMode::Time(30),
+    TestMode::Time(60),
+    TestMode::Words(25),
+    TestMode::Words(50),
+];
+
+/// The test everyone gets on a given day
+/// Mode and seed only depend on the date, so the words are the same for every user
+/// of the same word list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyChallenge {
+    /// Day of the challenge (UTC)
+    pub date: NaiveDate,
+    /// Mode of the test
+    pub mode: TestMode,
+    /// Seed of the words
+    pub seed: u64,
+}
+
+impl DailyChallenge {
+    /// Get the challenge of a given day
+    pub fn for_date(date: NaiveDate) -> Self {
+        let seed = mix(date.num_days_from_ce() as u64);
+        let mode = DAILY_MODES[(mix(seed) % DAILY_MODES.len() as u64) as usize];
+        Self { date, mode, seed }
+    }
+
+    /// Get today's challenge (the day changes at midnight UTC)
+    pub fn today() -> Self {
+        Self::for_date(Utc::now().date_naive())
+    }
+}
+
+/// Scramble a number (SplitMix64), so neighbouring days get unrelated seeds
+/// Written out instead of using a std hasher, whose output may change between Rust versions.
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{Challenge, DEFAULT_WORD_LIST, default_words};
+
+    #[test]
+    fn test_challenge_depends_only_on_the_date() {
+        let day = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let challenge = DailyChallenge::for_date(day);
+        assert_eq!(challenge, DailyChallenge::for_date(day));
+
+        // Pinned, so a change to the derivation (which would give users different tests) is noticed
+        assert_eq!(challenge.mode, TestMode::Words(50));
+        assert_eq!(challenge.seed, 13_164_964_059_275_510_465);
+        assert_ne!(DailyChallenge::for_date(day.succ_opt().unwrap()).seed, challenge.seed);
+    }
+
+    #[test]
+    fn test_words_of_a_day_are_pinned() {
+        // Everyone taking the challenge of a day has to get these words, whatever build they run
+        let day = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let challenge = DailyChallenge::for_date(day);
+        let words = default_words();
+        let engine = Challenge::new(challenge.mode, DEFAULT_WORD_LIST, &words, challenge.seed).engine(&words);
+        assert_eq!(engine.words.len(), 50);
+        assert_eq!(engine.words[..6], ["złoty", "problem", "stół", "firma", "być", "rzecz"]);
+    }
+}
@@ -1,6 +1,15 @@
+use serde::{Deserialize, Serialize};
 use std::time::Instant;
-use super::input::WordState;
-use super::metrics::TestMetrics;
+use super::clock::{Clock, SystemClock};
+use super::metrics::calculate_wpm;
+use super::{CharState, TestMetrics, WordState};
+
+/// Length of the window the live WPM is measured over (in seconds)
+const LIVE_WPM_WINDOW: f64 = 5.0;
+/// Longest allowed time test (in seconds)
+pub const MAX_SECONDS: u32 = 3600;
+/// Longest allowed words test
+pub const MAX_WORDS: u32 = 1000;
 
 /// Test state machine
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -14,7 +23,8 @@ pub enum TestState {
 }
 
 /// Test mode - either time-based or word count-based
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TestMode {
     /// Time-based test (in seconds)
     Time(u32),
@@ -74,11 +84,26 @@ pub struct TestEngine {
     pub result_saved: bool,
     /// Track which words had errors (for displaying red color)
     pub word_had_errors: Vec<bool>,
+    /// Final state of each completed word (for reviewing what was typed)
+    pub completed_words: Vec<WordState>,
+    /// Times of the correct keystrokes still standing (for the live WPM)
+    pub correct_keystrokes: Vec<Instant>,
+    /// How many times these words have been attempted (1 for a fresh test)
+    pub attempt: u32,
+    /// Seed the words were generated from (None for a fixed text)
+    pub seed: Option<u64>,
+    /// Where the start, end and keystroke times come from
+    clock: Box<dyn Clock>,
 }
 
 impl TestEngine {
     /// Create a new test engine
     pub fn new(mode: TestMode, words: Vec<String>) -> Self {
+        Self::with_clock(mode, words, SystemClock)
+    }
+
+    /// Create a test engine that reads the time from the given clock
+    pub fn with_clock(mode: TestMode, words: Vec<String>, clock: impl Clock + 'static) -> Self {
         let words_per_line = 6; // 5-6 words per line
 
         // Group words into lines
@@ -107,6 +132,11 @@ impl TestEngine {
             incorrect_chars: 0,
             result_saved: false,
             word_had_errors: vec![false; word_count],
+            completed_words: Vec::with_capacity(word_count),
+            correct_keystrokes: Vec::new(),
+            attempt: 1,
+            seed: None,
+            clock: Box::new(clock),
         }
     }
 
@@ -114,7 +144,7 @@ impl TestEngine {
     pub fn start(&mut self) {
         if self.state == TestState::NotStarted {
             self.state = TestState::InProgress;
-            self.start_time = Some(Instant::now());
+            self.start_time = Some(self.clock.now());
         }
     }
 
@@ -122,14 +152,14 @@ impl TestEngine {
     pub fn finish(&mut self) {
         if self.state == TestState::InProgress {
             self.state = TestState::Finished;
-            self.end_time = Some(Instant::now());
+            self.end_time = Some(self.clock.now());
         }
     }
 
     /// Get elapsed time in seconds
     pub fn elapsed_seconds(&self) -> f64 {
         if let Some(start) = self.start_time {
-            let end = self.end_time.unwrap_or_else(Instant::now);
+            let end = self.end_time.unwrap_or_else(|| self.clock.now());
             end.duration_since(start).as_secs_f64()
         } else {
             0.0
@@ -164,9 +194,12 @@ impl TestEngine {
             return;
         }
 
-        if let Some(word_state) = &mut self.current_word_state {
-            if word_state.add_char(ch) {
-                self.total_chars_typed += 1;
+        if let Some(word_state) = &mut self.current_word_state
+            && word_state.add_char(ch)
+        {
+            self.total_chars_typed += 1;
+            if word_state.char_states[word_state.cursor_pos - 1] == CharState::Correct {
+                self.correct_keystrokes.push(self.clock.now());
             }
         }
     }
@@ -178,7 +211,23 @@ impl TestEngine {
         }
 
         if let Some(word_state) = &mut self.current_word_state {
-            word_state.remove_char();
+            let removed = word_state.cursor_pos.checked_sub(1).map(|pos| word_state.char_states[pos]);
+            if word_state.remove_char() && removed == Some(CharState::Correct) {
+                self.correct_keystrokes.pop();
+            }
+        }
+    }
+
+    /// Delete everything typed in the current word
+    pub fn delete_word(&mut self) {
+        if self.state != TestState::InProgress {
+            return;
+        }
+
+        if let Some(word_state) = &mut self.current_word_state {
+            let removed_correct = word_state.clear();
+            let kept = self.correct_keystrokes.len().saturating_sub(removed_correct);
+            self.correct_keystrokes.truncate(kept);
         }
     }
 
@@ -189,15 +238,17 @@ impl TestEngine {
         }
 
         // Update stats from current word and track if it had errors
-        if let Some(word_state) = &self.current_word_state {
+        if let Some(word_state) = self.current_word_state.take() {
             self.correct_chars += word_state.correct_count();
             self.incorrect_chars += word_state.incorrect_count();
 
             // Mark if this word had any errors
-            let had_errors = word_state.incorrect_count() > 0;
+            let had_errors = word_state.has_errors();
             if self.current_word_index < self.word_had_errors.len() {
                 self.word_had_errors[self.current_word_index] = had_errors;
             }
+
+            self.completed_words.push(word_state);
         }
 
         // Move to next word in line
@@ -212,11 +263,7 @@ impl TestEngine {
         }
 
         // Initialize next word state or finish if done
-        if let Some(next_word) = self.words.get(self.current_word_index) {
-            self.current_word_state = Some(WordState::new(next_word.clone()));
-        } else {
-            self.current_word_state = None;
-        }
+        self.current_word_state = self.current_word().map(|w| WordState::new(w.to_string()));
 
         // Check if we should auto-finish
         if self.should_auto_finish() {
@@ -233,8 +280,36 @@ impl TestEngine {
         )
     }
 
-    /// Reset the test to initial state
+    /// Get the typing speed over the last few seconds, including the word being typed
+    /// The final result uses the cumulative `get_metrics()` instead.
+    pub fn live_wpm(&self) -> f64 {
+        self.live_wpm_at(self.end_time.unwrap_or_else(|| self.clock.now()))
+    }
+
+    /// Get the live WPM as of the given time
+    fn live_wpm_at(&self, now: Instant) -> f64 {
+        let Some(start) = self.start_time else {
+            return 0.0;
+        };
+
+        // Early in the test the window only reaches back to the start (but at least one second)
+        let window = now.duration_since(start).as_secs_f64().clamp(1.0, LIVE_WPM_WINDOW);
+        let recent = self
+            .correct_keystrokes
+            .iter()
+            .rev()
+            .take_while(|time| now.duration_since(**time).as_secs_f64() <= window)
+            .count();
+
+        calculate_wpm(recent, window)
+    }
+
+    /// Reset the test to initial state, keeping the same words
+    /// Counts as a new attempt only if typing had started.
     pub fn reset(&mut self) {
+        if self.start_time.is_some() {
+            self.attempt += 1;
+        }
         self.state = TestState::NotStarted;
         self.current_line_index = 0;
         self.current_word_in_line = 0;
@@ -247,12 +322,25 @@ impl TestEngine {
         self.incorrect_chars = 0;
         self.result_saved = false;
         self.word_had_errors = vec![false; self.words.len()];
+        self.completed_words.clear();
+        self.correct_keystrokes.clear();
+    }
+
+    /// Check if this is a retry of text that was already attempted
+    pub fn is_retry(&self) -> bool {
+        self.attempt > 1
+    }
+
+    /// Get the typed state of a completed word
+    pub fn completed_word(&self, index: usize) -> Option<&WordState> {
+        self.completed_words.get(index)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_engine_creation() {
@@ -274,4 +362,66 @@ mod tests {
         assert_eq!(engine.state, TestState::Finished);
         assert!(engine.end_time.is_some());
     }
+
+    #[test]
+    fn test_completed_words_keep_typed_chars() {
+        let words = vec!["ab".to_string(), "cd".to_string()];
+        let mut engine = TestEngine::new(TestMode::default_time(), words);
+
+        engine.type_char('a');
+        engine.type_char('x');
+        engine.next_word();
+
+        let completed = engine.completed_word(0).unwrap();
+        assert_eq!(completed.typed_char(1), Some('x'));
+        assert_eq!(completed.char_states[1], crate::test::CharState::Incorrect);
+        assert!(engine.word_had_errors[0]);
+    }
+
+    #[test]
+    fn test_reset_counts_attempts() {
+        let words = vec!["test".to_string()];
+        let mut engine = TestEngine::new(TestMode::default_words(), words);
+
+        // Resetting before typing doesn't count as a retry
+        engine.reset();
+        assert!(!engine.is_retry());
+
+        engine.type_char('t');
+        engine.reset();
+        assert_eq!(engine.attempt, 2);
+        assert!(engine.is_retry());
+        assert_eq!(engine.state, TestState::NotStarted);
+    }
+
+    #[test]
+    fn test_live_wpm_uses_recent_keystrokes() {
+        let words = vec!["abcdefghij".to_string()];
+        let mut engine = TestEngine::new(TestMode::default_time(), words);
+        engine.start();
+        let start = engine.start_time.unwrap();
+
+        // 5 correct characters early on, then 10 in the last 5 seconds of a 20 second test
+        engine.correct_keystrokes = (0..5).map(|_| start + Duration::from_secs(1)).collect();
+        engine.correct_keystrokes.extend((0..10).map(|i| start + Duration::from_millis(15_500 + i * 400)));
+
+        let now = start + Duration::from_secs(20);
+        // 10 characters (2 words) in 5 seconds = 24 WPM
+        assert!((engine.live_wpm_at(now) - 24.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_backspace_drops_correct_keystroke() {
+        let words = vec!["ab".to_string()];
+        let mut engine = TestEngine::new(TestMode::default_time(), words);
+
+        engine.type_char('a');
+        engine.type_char('x');
+        assert_eq!(engine.correct_keystrokes.len(), 1);
+
+        engine.backspace();
+        assert_eq!(engine.correct_keystrokes.len(), 1);
+        engine.backspace();
+        assert!(engine.correct_keystrokes.is_empty());
+    }
 }
@@ -16,6 +16,8 @@ pub struct WordState {
     pub target: String,
     /// State of each character
     pub char_states: Vec<CharState>,
+    /// Character actually typed at each position (None if untyped)
+    pub typed_chars: Vec<Option<char>>,
     /// Current cursor position in the word
     pub cursor_pos: usize,
 }
@@ -27,6 +29,7 @@ impl WordState {
         Self {
             target,
             char_states: vec![CharState::Untyped; len],
+            typed_chars: vec![None; len],
             cursor_pos: 0,
         }
     }
@@ -44,6 +47,7 @@ impl WordState {
             } else {
                 self.char_states[self.cursor_pos] = CharState::Incorrect;
             }
+            self.typed_chars[self.cursor_pos] = Some(ch);
             self.cursor_pos += 1;
             true
         } else {
@@ -56,12 +60,23 @@ impl WordState {
         if self.cursor_pos > 0 {
             self.cursor_pos -= 1;
             self.char_states[self.cursor_pos] = CharState::Untyped;
+            self.typed_chars[self.cursor_pos] = None;
             true
         } else {
             false
         }
     }
 
+    /// Remove everything typed in the word (Ctrl+Backspace)
+    /// Returns the number of correct characters removed
+    pub fn clear(&mut self) -> usize {
+        let removed_correct = self.correct_count();
+        self.char_states.fill(CharState::Untyped);
+        self.typed_chars.fill(None);
+        self.cursor_pos = 0;
+        removed_correct
+    }
+
     /// Check if word is complete (all chars typed)
     pub fn is_complete(&self) -> bool {
         self.cursor_pos >= self.char_states.len()
@@ -69,7 +84,7 @@ impl WordState {
 
     /// Check if word has any errors
     pub fn has_errors(&self) -> bool {
-        self.char_states.iter().any(|&s| s == CharState::Incorrect)
+        self.char_states.contains(&CharState::Incorrect)
     }
 
     /// Get number of correct characters
@@ -81,6 +96,11 @@ impl WordState {
     pub fn incorrect_count(&self) -> usize {
         self.char_states.iter().filter(|&&s| s == CharState::Incorrect).count()
     }
+
+    /// Get the character typed at a position, if any
+    pub fn typed_char(&self, pos: usize) -> Option<char> {
+        self.typed_chars.get(pos).copied().flatten()
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +127,15 @@ mod tests {
         let mut word = WordState::new("test".to_string());
         word.add_char('x');
         assert_eq!(word.char_states[0], CharState::Incorrect);
+        assert_eq!(word.typed_char(0), Some('x'));
+    }
+
+    #[test]
+    fn test_typed_chars_recorded() {
+        let mut word = WordState::new("test".to_string());
+        word.add_char('t');
+        word.add_char('a');
+        assert_eq!(word.typed_chars, vec![Some('t'), Some('a'), None, None]);
     }
 
     #[test]
@@ -115,6 +144,19 @@ mod tests {
         word.add_char('t');
         word.remove_char();
         assert_eq!(word.char_states[0], CharState::Untyped);
+        assert_eq!(word.typed_char(0), None);
+        assert_eq!(word.cursor_pos, 0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut word = WordState::new("test".to_string());
+        word.add_char('t');
+        word.add_char('x');
+        word.add_char('s');
+        assert_eq!(word.clear(), 2);
         assert_eq!(word.cursor_pos, 0);
+        assert!(word.char_states.iter().all(|&s| s == CharState::Untyped));
+        assert_eq!(word.typed_chars, vec![None; 4]);
     }
 }
@@ -1,5 +1,7 @@
+use serde::Serialize;
+
 /// Test metrics
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct TestMetrics {
     /// Words per minute
     pub wpm: f64,
@@ -2,8 +2,19 @@ pub mod words;
 pub mod engine;
 pub mod metrics;
 pub mod input;
+pub mod quotes;
+pub mod clock;
+pub mod script;
+pub mod daily;
+pub mod challenge;
+pub mod wordlists;
 
-pub use words::{load_words, generate_word_sequence};
-pub use engine::{TestEngine, TestMode, TestState};
+pub use words::{load_words, load_words_from_file, load_text_from_file, generate_word_sequence, seeded_rng, time_word_count};
+pub use engine::{TestEngine, TestMode, TestState, MAX_SECONDS, MAX_WORDS};
 pub use metrics::TestMetrics;
 pub use input::{CharState, WordState};
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use script::Script;
+pub use daily::DailyChallenge;
+pub use challenge::Challenge;
+pub use wordlists::{default_words, WordListEntry, WordListSource, WordLists, DEFAULT_WORD_LIST};
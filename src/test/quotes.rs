@@ -0,0 +1,29 @@
+/// Short public domain quotes for the quote mode
+pub const QUOTES: &[&str] = &[
+    "The only thing we have to fear is fear itself.",
+    "Well done is better than well said.",
+    "It does not matter how slowly you go as long as you do not stop.",
+    "In the middle of difficulty lies opportunity.",
+    "Whatever you are, be a good one.",
+    "Energy and persistence conquer all things.",
+    "We are what we repeatedly do. Excellence, then, is not an act, but a habit.",
+    "Nothing in life is to be feared, it is only to be understood.",
+    "The secret of getting ahead is getting started.",
+    "Tell me and I forget. Teach me and I remember. Involve me and I learn.",
+    "Lost time is never found again.",
+    "An investment in knowledge pays the best interest.",
+    "The journey of a thousand miles begins with one step.",
+    "What we think, we become.",
+    "Simplicity is the ultimate sophistication.",
+    "To be, or not to be, that is the question.",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quotes_have_words() {
+        assert!(QUOTES.iter().all(|quote| quote.split_whitespace().count() > 1));
+    }
+}
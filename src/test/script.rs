@@ -0,0 +1,240 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use super::clock::{Clock, ManualClock};
+use super::{TestEngine, TestMode, TestState};
+
+/// A key fed to the engine by a script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptKey {
+    /// Type a character
+    Char(char),
+    /// Move to the next word
+    Space,
+    /// Delete the last character
+    Backspace,
+    /// Delete the current word
+    DeleteWord,
+}
+
+/// One line of a script: wait, then press a key (or only wait)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptStep {
+    /// Time since the previous step
+    pub delay: Duration,
+    /// Key pressed after the delay
+    pub key: Option<ScriptKey>,
+}
+
+/// Timed keystrokes to drive a test without a terminal
+///
+/// One step per line, `+<delay> [key]`: the delay is in `ms` or `s` (`+120ms`, `+1.5s`),
+/// the key is a single character or one of `<space>`, `<backspace>` and `<delete-word>`.
+/// A line with only a delay just lets time pass, e.g. to run out a time test.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// ```text
+/// +0ms h
+/// +120ms i
+/// +150ms <space>
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Script {
+    /// Steps in the order they are played
+    pub steps: Vec<ScriptStep>,
+}
+
+impl Script {
+    /// Parse a script from its text
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut steps = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let step = parse_step(line).with_context(|| format!("line {}", index + 1))?;
+            steps.push(step);
+        }
+
+        Ok(Self { steps })
+    }
+
+    /// Load a script from a file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .context("Failed to read script file")?;
+        Self::parse(&content)
+    }
+
+    /// Play the script on a new test and return the engine as it was left
+    /// The test ends when the last word is done or, for a time test, exactly when the time is up.
+    /// Read the result with `get_metrics()`.
+    pub fn run(&self, mode: TestMode, words: Vec<String>) -> TestEngine {
+        let clock = ManualClock::new();
+        let mut engine = TestEngine::with_clock(mode, words, clock.clone());
+
+        for step in &self.steps {
+            wait(&mut engine, &clock, step.delay);
+
+            match step.key {
+                Some(ScriptKey::Char(ch)) => engine.type_char(ch),
+                Some(ScriptKey::Space) => engine.next_word(),
+                Some(ScriptKey::Backspace) => engine.backspace(),
+                Some(ScriptKey::DeleteWord) => engine.delete_word(),
+                None => {}
+            }
+        }
+
+        engine
+    }
+}
+
+/// Let time pass, finishing a time test at the moment it runs out
+fn wait(engine: &mut TestEngine, clock: &ManualClock, delay: Duration) {
+    if let TestMode::Time(seconds) = engine.mode
+        && engine.state == TestState::InProgress
+        && let Some(start) = engine.start_time
+    {
+        let left = Duration::from_secs(seconds as u64).saturating_sub(clock.now() - start);
+        if delay >= left {
+            clock.advance(left);
+            engine.finish();
+            clock.advance(delay - left);
+            return;
+        }
+    }
+
+    clock.advance(delay);
+}
+
+/// Parse a single `+<delay> [key]` line
+fn parse_step(line: &str) -> Result<ScriptStep> {
+    let (delay, key) = match line.split_once(char::is_whitespace) {
+        Some((delay, key)) => (delay, Some(key.trim())),
+        None => (line, None),
+    };
+
+    Ok(ScriptStep {
+        delay: parse_delay(delay)?,
+        key: key.map(parse_key).transpose()?,
+    })
+}
+
+/// Parse a delay like `+120ms` or `+1.5s`
+fn parse_delay(delay: &str) -> Result<Duration> {
+    let Some(delay) = delay.strip_prefix('+') else {
+        bail!("delay '{}' must start with '+'", delay);
+    };
+    let (number, seconds_per_unit) = if let Some(number) = delay.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = delay.strip_suffix('s') {
+        (number, 1.0)
+    } else {
+        bail!("delay '+{}' needs a unit (ms or s)", delay);
+    };
+
+    number
+        .parse::<f64>()
+        .ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * seconds_per_unit).ok())
+        .with_context(|| format!("invalid delay '+{}'", delay))
+}
+
+/// Parse a key: a single character or a named key
+fn parse_key(key: &str) -> Result<ScriptKey> {
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => return Ok(ScriptKey::Char(ch)),
+        (None, _) => bail!("missing key"),
+        _ => {}
+    }
+
+    match key {
+        "<space>" => Ok(ScriptKey::Space),
+        "<backspace>" => Ok(ScriptKey::Backspace),
+        "<delete-word>" => Ok(ScriptKey::DeleteWord),
+        _ => bail!("unknown key '{}'", key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    /// Type each word at a steady pace, one character every `interval`
+    fn typing(text: &str, interval: &str) -> String {
+        let steps: Vec<String> = text
+            .chars()
+            .map(|ch| if ch == ' ' { "<space>".to_string() } else { ch.to_string() })
+            .map(|key| format!("+{} {}", interval, key))
+            .collect();
+        steps.join("\n")
+    }
+
+    #[test]
+    fn test_parse_script() {
+        let script = Script::parse("# warm up\n+0ms a\n\n+1.5s <space>\n+30s").unwrap();
+        assert_eq!(
+            script.steps,
+            vec![
+                ScriptStep { delay: Duration::ZERO, key: Some(ScriptKey::Char('a')) },
+                ScriptStep { delay: Duration::from_millis(1500), key: Some(ScriptKey::Space) },
+                ScriptStep { delay: Duration::from_secs(30), key: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Script::parse("120ms a").is_err());
+        assert!(Script::parse("+120 a").is_err());
+        assert!(Script::parse("+-1s a").is_err());
+        assert!(Script::parse("+1s <enter>").is_err());
+
+        let err = Script::parse("+0ms a\n+1..5s b").unwrap_err();
+        assert_eq!(format!("{:#}", err), "line 2: invalid delay '+1..5s'");
+    }
+
+    #[test]
+    fn test_words_test_scoring() {
+        // The first key starts the clock, the 11 after it take 200ms each
+        let script = Script::parse(&typing("hello world ", "200ms")).unwrap();
+        let engine = script.run(TestMode::Words(2), words("hello world"));
+
+        assert_eq!(engine.state, TestState::Finished);
+        assert!((engine.elapsed_seconds() - 2.2).abs() < 1e-9);
+        let metrics = engine.get_metrics();
+        // 10 correct characters (2 words) in 2.2 seconds
+        assert!((metrics.wpm - 2.0 / (2.2 / 60.0)).abs() < 1e-9);
+        assert_eq!(metrics.accuracy, 100.0);
+    }
+
+    #[test]
+    fn test_corrections_count() {
+        let script = Script::parse("+0ms a\n+100ms x\n+100ms <backspace>\n+100ms b\n+100ms <space>").unwrap();
+        let engine = script.run(TestMode::Words(1), words("ab"));
+
+        assert_eq!(engine.state, TestState::Finished);
+        assert_eq!(engine.correct_chars, 2);
+        assert_eq!(engine.total_chars_typed, 3);
+    }
+
+    #[test]
+    fn test_time_test_finishes_when_time_is_up() {
+        let script = Script::parse("+0ms a\n+500ms b\n+10s\n+1s c").unwrap();
+        let engine = script.run(TestMode::Time(5), words("abc def"));
+
+        assert_eq!(engine.state, TestState::Finished);
+        assert!((engine.elapsed_seconds() - 5.0).abs() < 1e-9);
+        // Keys after the end are ignored
+        assert_eq!(engine.total_chars_typed, 2);
+    }
+}
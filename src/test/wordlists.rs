@@ -0,0 +1,176 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::words::load_words_from_file;
+
+/// Word list used when none is configured (and by the daily challenge)
+pub const DEFAULT_WORD_LIST: &str = "polish";
+
+/// Word lists compiled into the binary: name and JSON array of words
+const BUNDLED: &[(&str, &str)] = &[
+    ("english", include_str!("../../wordlists/english.json")),
+    ("english_1k", include_str!("../../wordlists/english_1k.json")),
+    ("english_10k", include_str!("../../wordlists/english_10k.json")),
+    ("german", include_str!("../../wordlists/german.json")),
+    ("polish", include_str!("../../wordlists/polish.json")),
+];
+
+/// Where a word list comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordListSource {
+    /// Compiled into the binary
+    Bundled(&'static str),
+    /// A JSON file in the user's word list directory
+    User(PathBuf),
+}
+
+/// A word list that can be picked by name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordListEntry {
+    /// Name of the list (a user list is named after its file, without `.json`)
+    pub name: String,
+    /// Where its words come from
+    pub source: WordListSource,
+}
+
+/// The word lists to choose from: the bundled ones, then the user's
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordLists {
+    /// Lists in the order they are offered
+    pub entries: Vec<WordListEntry>,
+}
+
+impl WordLists {
+    /// Get the bundled lists only
+    pub fn bundled() -> Self {
+        let entries = BUNDLED
+            .iter()
+            .map(|(name, json)| WordListEntry { name: name.to_string(), source: WordListSource::Bundled(json) })
+            .collect();
+        Self { entries }
+    }
+
+    /// Get the bundled lists and the `*.json` files in `dirs`, sorted by name
+    /// Missing directories have no lists. A name is taken by the first list that has it,
+    /// so a user list can't replace a bundled one, and the first directory wins over later ones.
+    pub fn discover(dirs: &[PathBuf]) -> Self {
+        let mut lists = Self::bundled();
+        let bundled = lists.entries.len();
+
+        for dir in dirs {
+            for path in list_files(dir) {
+                let Some(name) = path.file_stem().map(|name| name.to_string_lossy().into_owned()) else {
+                    continue;
+                };
+                if lists.get(&name).is_none() {
+                    lists.entries.push(WordListEntry { name, source: WordListSource::User(path) });
+                }
+            }
+        }
+
+        lists.entries[bundled..].sort_by(|a, b| a.name.cmp(&b.name));
+        lists
+    }
+
+    /// Find a list by name
+    pub fn get(&self, name: &str) -> Option<&WordListEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// Load the words of a list by name, or of a file if no list has that name
+    pub fn load(&self, name: &str) -> Result<Vec<String>> {
+        match self.get(name).map(|entry| &entry.source) {
+            Some(WordListSource::Bundled(json)) => {
+                serde_json::from_str(json).with_context(|| format!("Failed to parse bundled word list '{}'", name))
+            }
+            Some(WordListSource::User(path)) => load_words_from_file(path),
+            None => load_words_from_file(name),
+        }
+    }
+
+    /// Get the name of the list after (or before) `name`, wrapping around
+    /// A name that isn't in the registry (e.g. a file path) moves to the first list.
+    pub fn cycle(&self, name: &str, forward: bool) -> &str {
+        let count = self.entries.len();
+        let index = match self.entries.iter().position(|entry| entry.name == name) {
+            Some(index) if forward => (index + 1) % count,
+            Some(index) => (index + count - 1) % count,
+            None => 0,
+        };
+        &self.entries[index].name
+    }
+}
+
+/// Get the words of the default list (always available, as it is bundled)
+pub fn default_words() -> Vec<String> {
+    WordLists::bundled().load(DEFAULT_WORD_LIST).unwrap_or_default()
+}
+
+/// Get the `*.json` files in a directory (none if it can't be read)
+fn list_files(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect()
+}
+
+impl Default for WordLists {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_lists_load() {
+        let lists = WordLists::bundled();
+        for entry in &lists.entries {
+            let words = lists.load(&entry.name).unwrap();
+            assert!(!words.is_empty(), "{} is empty", entry.name);
+            assert!(words.iter().all(|word| !word.is_empty() && !word.contains(char::is_whitespace)));
+            let unique: std::collections::HashSet<_> = words.iter().collect();
+            assert_eq!(unique.len(), words.len(), "{} has duplicates", entry.name);
+        }
+        assert!(lists.get(DEFAULT_WORD_LIST).is_some());
+        assert_eq!(lists.load("english_1k").unwrap().len(), 1000);
+        assert_eq!(lists.load("english_10k").unwrap().len(), 10000);
+    }
+
+    #[test]
+    fn test_discover_user_lists() {
+        let root = std::env::temp_dir().join(format!("termotype-wordlists-{}", std::process::id()));
+        let (home, system) = (root.join("home"), root.join("system"));
+        fs::create_dir_all(&home).unwrap();
+        fs::create_dir_all(&system).unwrap();
+        fs::write(home.join("rust.json"), r#"["fn", "impl", "trait"]"#).unwrap();
+        fs::write(home.join("english.json"), r#"["shadowed"]"#).unwrap();
+        fs::write(home.join("notes.txt"), "not a list").unwrap();
+        fs::write(system.join("rust.json"), r#"["system"]"#).unwrap();
+        fs::write(system.join("go.json"), r#"["func"]"#).unwrap();
+
+        let lists = WordLists::discover(&[home, root.join("missing"), system]);
+        let rust = lists.load("rust");
+        fs::remove_dir_all(&root).unwrap();
+
+        let names: Vec<&str> = lists.entries[BUNDLED.len()..].iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["go", "rust"]);
+        assert_eq!(rust.unwrap().len(), 3);
+        assert_ne!(lists.load("english").unwrap(), vec!["shadowed".to_string()]);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let lists = WordLists::bundled();
+        assert_eq!(lists.cycle("english", true), "english_1k");
+        assert_eq!(lists.cycle("english", false), "polish");
+        assert_eq!(lists.cycle("polish", true), "english");
+        assert_eq!(lists.cycle("lists/mine.json", true), "english");
+    }
+}
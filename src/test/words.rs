@@ -1,21 +1,11 @@
 use anyhow::{Context, Result};
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use std::fs;
 use std::path::Path;
 
-/// Hardcoded fallback list of Polish words
-const FALLBACK_WORDS: &[&str] = &[
-    "ale", "bez", "być", "czy", "dla", "dom", "gdy", "już", "jak", "jego",
-    "jest", "jeden", "jeszcze", "która", "może", "który", "mieć", "nasz", "nie", "najpierw",
-    "oraz", "pierwszy", "pod", "przez", "przy", "ponieważ", "który", "się", "swój", "tak",
-    "tam", "ten", "teraz", "tylko", "właśnie", "bardzo", "gdzie", "jestem", "można", "musieć",
-    "nowy", "podczas", "ponad", "przed", "również", "rzecz", "sposób", "według", "wiele", "właśnie",
-    "zawsze", "ziemia", "życie", "świat", "czas", "człowiek", "praca", "system", "grupa", "problem",
-    "program", "firma", "produkt", "projekt", "funkcja", "metoda", "wynik", "proces", "przykład", "część",
-    "miejsce", "sprawy", "strona", "forma", "droga", "środek", "przypadek", "liczba", "wartość", "stopień",
-    "różny", "ostatni", "duży", "mały", "wielki", "nowy", "stary", "dobry", "zły", "czarny",
-    "biały", "długi", "krótki", "wysoki", "niski", "szeroki", "wąski", "głęboki", "płytki", "ciężki",
-];
+use super::wordlists::default_words;
 
 /// Load words from a JSON file
 /// Returns a vector of words
@@ -33,27 +23,46 @@ pub fn load_words_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
     Ok(words)
 }
 
-/// Load words with fallback to hardcoded list
-/// First tries to load from the specified file, falls back to FALLBACK_WORDS if it fails
+/// Load a text to type word by word from a plain text file
+pub fn load_text_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    let content = fs::read_to_string(path)
+        .context("Failed to read text file")?;
+
+    let words: Vec<String> = content.split_whitespace().map(String::from).collect();
+    if words.is_empty() {
+        anyhow::bail!("Text file is empty");
+    }
+
+    Ok(words)
+}
+
+/// Load words with fallback to the default list
+/// First tries to load from the specified file, falls back to the bundled default list if it fails
 pub fn load_words<P: AsRef<Path>>(path: P) -> Vec<String> {
-    load_words_from_file(path).unwrap_or_else(|_| {
-        // Fallback to hardcoded list
-        FALLBACK_WORDS
-            .iter()
-            .map(|s| s.to_string())
-            .collect()
-    })
+    load_words_from_file(path).unwrap_or_else(|_| default_words())
+}
+
+/// Get the number of words needed for a time test: enough for 240 WPM (at least 100)
+pub fn time_word_count(seconds: u32) -> usize {
+    (seconds as usize * 4).max(100)
+}
+
+/// Get the random source of a seeded test
+/// ChaCha8's output is fixed by its specification, while `StdRng` may change with the rand version
+/// or platform, so a seed or a shared code brings back the same test on any build.
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
 }
 
 /// Generate a sequence of random words for the test
 /// `count` - number of words to generate
 /// `words` - source word list
-pub fn generate_word_sequence(count: usize, words: &[String]) -> Vec<String> {
-    let mut rng = rand::thread_rng();
+/// `rng` - random source (seeded to repeat a test)
+pub fn generate_word_sequence<R: Rng>(count: usize, words: &[String], rng: &mut R) -> Vec<String> {
     let mut sequence = Vec::with_capacity(count);
 
     for _ in 0..count {
-        if let Some(word) = words.choose(&mut rng) {
+        if let Some(word) = words.choose(rng) {
             sequence.push(word.clone());
         }
     }
@@ -66,14 +75,33 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_fallback_words_not_empty() {
-        assert!(!FALLBACK_WORDS.is_empty());
+    fn test_missing_file_falls_back_to_default_list() {
+        assert_eq!(load_words("/nonexistent/words.json"), default_words());
+        assert!(!default_words().is_empty());
     }
 
     #[test]
     fn test_generate_word_sequence() {
         let words = vec!["test".to_string(), "word".to_string()];
-        let sequence = generate_word_sequence(10, &words);
+        let sequence = generate_word_sequence(10, &words, &mut rand::thread_rng());
         assert_eq!(sequence.len(), 10);
     }
+
+    #[test]
+    fn test_seeded_sequence_repeats() {
+        let words = default_words();
+        let first = generate_word_sequence(20, &words, &mut seeded_rng(7));
+        let second = generate_word_sequence(20, &words, &mut seeded_rng(7));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_seeded_rng_is_stable() {
+        use rand::RngCore;
+
+        // Seeds are saved with every result, so they must keep giving the same test
+        let words: Vec<String> = ["a", "b", "c", "d", "e", "f", "g", "h"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(generate_word_sequence(10, &words, &mut seeded_rng(42)).concat(), "fbgdfcfbcb");
+        assert_eq!(seeded_rng(42).next_u64(), 12_578_764_544_318_200_737);
+    }
 }
@@ -0,0 +1,143 @@
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::Result;
+use crossterm::{
+    cursor::{SetCursorStyle, Show},
+    event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+/// Set when the interface is drawn on stderr, keeping stdout for the printed result
+static DRAW_ON_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Draw the interface on stderr instead of stdout
+/// Lets `termotype --json > result.json` show the test while only the result goes to the file.
+pub fn draw_on_stderr() {
+    DRAW_ON_STDERR.store(true, Ordering::Relaxed);
+}
+
+/// Get the stream the interface is drawn on
+pub fn output() -> Box<dyn Write + Send> {
+    if DRAW_ON_STDERR.load(Ordering::Relaxed) {
+        Box::new(io::stderr())
+    } else {
+        Box::new(io::stdout())
+    }
+}
+
+/// Switch the terminal to raw mode on the alternate screen, with mouse and focus events
+pub fn setup() -> Result<()> {
+    enable_raw_mode()?;
+    execute!(output(), EnterAlternateScreen, EnableMouseCapture, EnableFocusChange)?;
+    Ok(())
+}
+
+/// Give the terminal back to the shell in the state it was before `setup`
+pub fn restore() -> Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        output(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableFocusChange,
+        SetCursorStyle::DefaultUserShape,
+        Show
+    )?;
+    Ok(())
+}
+
+/// Restore the terminal before a panic report is printed
+/// Without this the report lands on the alternate screen and the shell is left in raw mode.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = restore();
+        default_hook(info);
+    }));
+}
+
+#[cfg(unix)]
+mod signals {
+    use anyhow::Result;
+    use signal_hook::consts::{SIGHUP, SIGTERM, SIGTSTP};
+    use signal_hook::iterator::Signals as SignalIterator;
+    use signal_hook::low_level;
+
+    /// What the application should do about the signals received since the last check
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SignalAction {
+        /// Nothing was received
+        None,
+        /// Save and exit (SIGTERM)
+        Exit,
+        /// Save and exit, the terminal being gone (SIGHUP)
+        HangUp,
+        /// Stop until resumed (SIGTSTP)
+        Suspend,
+    }
+
+    /// Signals caught while the UI is running
+    pub struct Signals {
+        signals: SignalIterator,
+    }
+
+    impl Signals {
+        /// Start catching SIGTERM, SIGHUP and SIGTSTP
+        pub fn new() -> Result<Self> {
+            Ok(Self { signals: SignalIterator::new([SIGTERM, SIGHUP, SIGTSTP])? })
+        }
+
+        /// Check the signals received since the last call, without blocking
+        pub fn check(&mut self) -> SignalAction {
+            action_for(self.signals.pending())
+        }
+    }
+
+    /// Get what to do about a batch of signals
+    /// A hang-up wins over exiting, which wins over suspending, whatever the order they arrived in.
+    fn action_for(signals: impl IntoIterator<Item = i32>) -> SignalAction {
+        let mut action = SignalAction::None;
+        for signal in signals {
+            action = match signal {
+                SIGHUP => SignalAction::HangUp,
+                _ if action == SignalAction::HangUp => action,
+                SIGTSTP if action == SignalAction::None => SignalAction::Suspend,
+                SIGTSTP => action,
+                _ => SignalAction::Exit,
+            };
+        }
+        action
+    }
+
+    /// Stop the process like Ctrl+Z in a normal shell, restoring the terminal first
+    /// Returns once the process is resumed (SIGCONT), with the terminal set up again.
+    /// The caller must redraw the whole screen afterwards.
+    pub fn suspend() -> Result<()> {
+        super::restore()?;
+        // Raw mode delivers Ctrl+Z as a key, and SIGTSTP itself is caught, so stop the default way
+        low_level::emulate_default_handler(SIGTSTP)?;
+        super::setup()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_signal_priority() {
+            assert_eq!(action_for([]), SignalAction::None);
+            assert_eq!(action_for([SIGTSTP]), SignalAction::Suspend);
+            assert_eq!(action_for([SIGTSTP, SIGTSTP]), SignalAction::Suspend);
+            assert_eq!(action_for([SIGTSTP, SIGTERM]), SignalAction::Exit);
+            assert_eq!(action_for([SIGTERM, SIGTSTP]), SignalAction::Exit);
+            assert_eq!(action_for([SIGHUP]), SignalAction::HangUp);
+            assert_eq!(action_for([SIGHUP, SIGTERM, SIGTSTP]), SignalAction::HangUp);
+            assert_eq!(action_for([SIGTERM, SIGHUP]), SignalAction::HangUp);
+        }
+    }
+}
+
+#[cfg(unix)]
+pub use signals::{suspend, SignalAction, Signals};
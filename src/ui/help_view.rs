@@ -0,0 +1,169 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Messages;
+use crate::keymap::{Action, Keymap};
+use crate::ui::Tab;
+
+/// A fixed key label with a function picking its localized description
+type FixedKey = (&'static str, fn(&Messages) -> &'static str);
+
+/// Keys handled directly by the Test tab (not remappable)
+const TEST_KEYS: &[FixedKey] = &[
+    ("Space", |msg| msg.key_next_word),
+    ("Backspace", |msg| msg.key_delete_char),
+];
+
+/// Keys handled directly by the Options tab (not remappable)
+const OPTIONS_KEYS: &[FixedKey] = &[
+    ("↑/↓ j/k", |msg| msg.key_select_setting),
+    ("←/→ h/l", |msg| msg.key_change_setting),
+    ("Enter", |msg| msg.key_edit_setting),
+];
+
+/// Keys used while typing a setting value (not remappable)
+const EDITING_KEYS: &[FixedKey] = &[
+    ("Enter", |msg| msg.key_apply_value),
+    ("Esc", |msg| msg.key_cancel_edit),
+];
+
+/// A titled group of key descriptions in the help popup
+#[derive(Debug, Clone, PartialEq)]
+struct Section {
+    title: &'static str,
+    /// Key label and description of each entry
+    entries: Vec<(String, &'static str)>,
+}
+
+/// Build a one-line hint such as "Start typing | Enter: Restart test | ?: Show this help"
+/// Actions without keys are left out; the popup lists every key.
+pub fn hint(app: &App, prefix: &str, actions: &[Action]) -> String {
+    let msg = app.messages();
+    let mut parts = vec![prefix.to_string()];
+    // Only the first key of each action that works right now, to keep the line short
+    let typing = app.accepts_typing();
+    parts.extend(actions.iter().filter_map(|action| {
+        let mut keys = app.keymap.keys(*action).iter().filter(|key| !(typing && key.types_text()));
+        keys.next().map(|key| format!("{}: {}", key, action.description(msg)))
+    }));
+    parts.join(" | ")
+}
+
+/// Build the help sections for a tab from the active keymap
+fn sections(keymap: &Keymap, tab: Tab, msg: &Messages) -> Vec<Section> {
+    let action_entries = |global: bool| -> Vec<(String, &'static str)> {
+        Action::all()
+            .into_iter()
+            .filter(|action| action.is_global() == global)
+            .filter_map(|action| keymap.key_label(action).map(|keys| (keys, action.description(msg))))
+            .collect()
+    };
+    let fixed_entries = |keys: &[FixedKey]| -> Vec<(String, &'static str)> {
+        keys.iter().map(|(key, description)| (key.to_string(), description(msg))).collect()
+    };
+
+    let mut sections = vec![Section { title: msg.help_global, entries: action_entries(true) }];
+    match tab {
+        Tab::Test => {
+            let mut entries = fixed_entries(TEST_KEYS);
+            entries.extend(action_entries(false));
+            sections.push(Section { title: msg.tab_test, entries });
+        }
+        Tab::Stats => sections.push(Section { title: msg.tab_stats, entries: Vec::new() }),
+        Tab::Options => {
+            sections.push(Section { title: msg.tab_options, entries: fixed_entries(OPTIONS_KEYS) });
+            sections.push(Section { title: msg.help_editing, entries: fixed_entries(EDITING_KEYS) });
+        }
+    }
+    sections
+}
+
+/// Render the help popup over the current tab
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let msg = app.messages();
+    let p = app.palette();
+    let sections = sections(&app.keymap, app.current_tab, msg);
+
+    let key_width = sections
+        .iter()
+        .flat_map(|section| section.entries.iter())
+        .map(|(keys, _)| keys.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut content = Vec::new();
+    for section in &sections {
+        if !content.is_empty() {
+            content.push(Line::from(""));
+        }
+        content.push(Line::from(Span::styled(
+            section.title,
+            Style::default().fg(p.accent).add_modifier(Modifier::BOLD),
+        )));
+        if section.entries.is_empty() {
+            content.push(Line::from(Span::styled(
+                format!("  {}", msg.help_no_tab_keys),
+                Style::default().fg(p.dim),
+            )));
+        }
+        for (keys, description) in &section.entries {
+            content.push(Line::from(vec![
+                Span::styled(format!("  {:<width$}  ", keys, width = key_width), Style::default().fg(p.highlight)),
+                Span::styled(*description, Style::default().fg(p.text)),
+            ]));
+        }
+    }
+
+    let popup = popup_area(area, 60, content.len() as u16 + 2);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", msg.help))
+        .title_bottom(Line::from(format!(" {} ", msg.help_close)).alignment(Alignment::Center))
+        .style(Style::default().fg(p.accent));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(content).block(block), popup);
+}
+
+/// Get a centered rectangle of at most the given size
+fn popup_area(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::ENGLISH;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_sections_follow_the_keymap() {
+        let (keymap, _) = Keymap::from_config(&BTreeMap::from([("restart".to_string(), vec!["ctrl+r".to_string()])]));
+        let sections = sections(&keymap, Tab::Test, &ENGLISH);
+
+        assert_eq!(sections.len(), 2);
+        assert!(sections[0].entries.contains(&("q/Esc/Ctrl+c/Ctrl+q".to_string(), ENGLISH.action_quit)));
+        assert!(sections[1].entries.contains(&("Ctrl+r".to_string(), ENGLISH.action_restart)));
+        // Restart only works in the Test tab
+        assert!(!sections[0].entries.iter().any(|(_, description)| *description == ENGLISH.action_restart));
+    }
+
+    #[test]
+    fn test_popup_area_fits_the_screen() {
+        assert_eq!(popup_area(Rect::new(0, 0, 100, 40), 60, 20), Rect::new(20, 10, 60, 20));
+        assert_eq!(popup_area(Rect::new(0, 0, 40, 10), 60, 20), Rect::new(0, 0, 40, 10));
+    }
+}
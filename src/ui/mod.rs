@@ -2,5 +2,45 @@ pub mod tabs;
 pub mod test_view;
 pub mod stats_view;
 pub mod options_view;
+pub mod help_view;
+pub mod theme;
+#[cfg(test)]
+mod snapshots;
 
 pub use tabs::{render_tabs, split_screen, Tab};
+pub use test_view::{CaretStyle, ErrorStyle};
+pub use theme::Theme;
+
+use ratatui::Frame;
+
+use crate::app::App;
+
+/// Draw the whole interface for the current app state
+/// `caret_visible` is the caret blink state for this frame (see `App::caret_visible`).
+pub fn draw(f: &mut Frame, app: &App, caret_visible: bool) {
+    // Focus mode shows only the words while typing
+    if app.focus_active() {
+        test_view::render_focused(f, app, caret_visible, f.area());
+        return;
+    }
+
+    // Split screen into tab bar and content area
+    let (tabs_area, content_area) = split_screen(f.area());
+
+    // Render tabs, with any problems on the bottom border
+    let notices: Vec<&str> = [app.notice.as_deref(), app.word_list_warning.as_deref()].into_iter().flatten().collect();
+    let notice = (!notices.is_empty()).then(|| notices.join(" | "));
+    render_tabs(f, tabs_area, app.current_tab, app.messages(), app.palette(), notice.as_deref());
+
+    // Render content based on current tab
+    match app.current_tab {
+        Tab::Test => test_view::render(f, app, caret_visible, content_area),
+        Tab::Stats => stats_view::render(f, app, content_area),
+        Tab::Options => options_view::render(f, app, content_area),
+    }
+
+    // The help popup is drawn over everything else
+    if app.show_help {
+        help_view::render(f, app, f.area());
+    }
+}
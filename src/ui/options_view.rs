@@ -1,77 +1,121 @@
+use std::rc::Rc;
+
 use ratatui::{
-    layout::{Constraint, Layout, Rect, Alignment},
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Layout, Position, Rect, Alignment},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
 use crate::app::App;
-use crate::test::TestMode;
+use crate::ui::help_view;
+use crate::keymap::Action;
+use crate::settings::{Category, Setting, SettingKind};
+
+/// Width of the setting label column
+const LABEL_WIDTH: usize = 26;
+
+/// A row of the settings list
+#[derive(Debug, Clone, Copy)]
+enum Row {
+    /// Empty line between categories
+    Blank,
+    /// Category heading
+    Header(Category),
+    /// Setting with its index in `Setting::all()`
+    Item(usize, Setting),
+}
 
 /// Render the options view
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
-    let chunks = Layout::default()
+    let chunks = layout(area);
+
+    render_settings_list(f, app, chunks[0]);
+    render_footer(f, app, chunks[1]);
+}
+
+/// Split the options view into the settings list and the footer
+fn layout(area: Rect) -> Rc<[Rect]> {
+    Layout::default()
         .constraints([
-            Constraint::Length(10),  // Mode selection
-            Constraint::Min(5),      // Instructions
+            Constraint::Min(5),      // Settings list
+            Constraint::Length(3),   // Hint or validation error
         ])
-        .split(area);
+        .split(area)
+}
+
+/// Build the rows of the settings list, grouped by category
+fn rows() -> Vec<Row> {
+    let mut rows = Vec::new();
+    let mut current_category = None;
 
-    render_mode_selection(f, app, chunks[0]);
-    render_instructions(f, chunks[1]);
+    for (index, setting) in Setting::all().into_iter().enumerate() {
+        if current_category != Some(setting.category()) {
+            if current_category.is_some() {
+                rows.push(Row::Blank);
+            }
+            rows.push(Row::Header(setting.category()));
+            current_category = Some(setting.category());
+        }
+        rows.push(Row::Item(index, setting));
+    }
+
+    rows
+}
+
+/// Get the first visible row so the selected setting stays on screen
+fn scroll_offset(rows: &[Row], selected: usize, height: u16) -> usize {
+    let selected_row = rows
+        .iter()
+        .position(|row| matches!(row, Row::Item(index, _) if *index == selected))
+        .unwrap_or(0);
+
+    (selected_row + 1).saturating_sub(height as usize)
 }
 
-/// Render mode selection
-fn render_mode_selection(f: &mut Frame, app: &App, area: Rect) {
+/// Find the setting at the given screen position
+/// Returns its index in `Setting::all()`
+pub fn setting_at(app: &App, area: Rect, column: u16, row: u16) -> Option<usize> {
+    let inner = Block::default().borders(Borders::ALL).inner(layout(area)[0]);
+    if !inner.contains(Position::new(column, row)) {
+        return None;
+    }
+
+    let rows = rows();
+    let offset = scroll_offset(&rows, app.settings_menu.selected, inner.height);
+    match rows.get(offset + (row - inner.y) as usize) {
+        Some(Row::Item(index, _)) => Some(*index),
+        _ => None,
+    }
+}
+
+/// Render the scrollable list of settings
+fn render_settings_list(f: &mut Frame, app: &App, area: Rect) {
+    let msg = app.messages();
+    let p = app.palette();
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Test Mode")
-        .style(Style::default().fg(Color::Cyan));
+        .title(msg.settings)
+        .style(Style::default().fg(p.accent));
+    let inner = block.inner(area);
 
-    let is_time_mode = matches!(app.test_mode, TestMode::Time(_));
+    let rows = rows();
+    let offset = scroll_offset(&rows, app.settings_menu.selected, inner.height);
 
-    let content = vec![
-        Line::from(""),
-        Line::from(vec![
-            Span::raw("  "),
-            if is_time_mode {
-                Span::styled("▶ ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-            } else {
-                Span::raw("  ")
-            },
-            Span::styled(
-                "30 Seconds",
-                if is_time_mode {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::White)
-                },
-            ),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::raw("  "),
-            if !is_time_mode {
-                Span::styled("▶ ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-            } else {
-                Span::raw("  ")
-            },
-            Span::styled(
-                "30 Words",
-                if !is_time_mode {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::White)
-                },
-            ),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "  Press 't' or 'w' to switch modes",
-            Style::default().fg(Color::DarkGray),
-        )),
-    ];
+    let content: Vec<Line> = rows
+        .iter()
+        .skip(offset)
+        .take(inner.height as usize)
+        .map(|row| match row {
+            Row::Blank => Line::from(""),
+            Row::Header(category) => Line::from(Span::styled(
+                format!("  {}", category.name(msg)),
+                Style::default().fg(p.accent).add_modifier(Modifier::BOLD),
+            )),
+            Row::Item(index, setting) => setting_line(app, *setting, *index == app.settings_menu.selected),
+        })
+        .collect();
 
     let paragraph = Paragraph::new(content)
         .block(block)
@@ -80,39 +124,87 @@ fn render_mode_selection(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
-/// Render instructions
-fn render_instructions(f: &mut Frame, area: Rect) {
+/// Build the line for a single setting
+fn setting_line(app: &App, setting: Setting, is_selected: bool) -> Line<'static> {
+    let p = app.palette();
+    let highlight = Style::default().fg(p.highlight).add_modifier(Modifier::BOLD);
+
+    let marker = if is_selected {
+        Span::styled("  ▶ ", highlight)
+    } else {
+        Span::raw("    ")
+    };
+
+    let label = Span::styled(
+        format!("{:<width$}", setting.label(app), width = LABEL_WIDTH),
+        if is_selected { highlight } else { Style::default().fg(p.text) },
+    );
+
+    let value = match &app.settings_menu.input {
+        Some(input) if is_selected => Span::styled(
+            format!("{}_", input),
+            Style::default().fg(p.highlight).add_modifier(Modifier::UNDERLINED),
+        ),
+        _ => {
+            let value = setting.value(app);
+            let text = if is_selected && setting.kind() != SettingKind::Text {
+                format!("◀ {} ▶", value)
+            } else {
+                value
+            };
+            Span::styled(text, Style::default().fg(if is_selected { p.highlight } else { p.muted }))
+        }
+    };
+
+    Line::from(vec![marker, label, value])
+}
+
+/// Render the key hint, or the validation error of the last edit
+fn render_footer(f: &mut Frame, app: &App, area: Rect) {
+    let msg = app.messages();
+    let p = app.palette();
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Instructions")
-        .style(Style::default().fg(Color::White));
-
-    let content = vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "  Keyboard Shortcuts:",
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-        )),
-        Line::from(""),
-        Line::from(Span::raw("  t - Switch to Time mode (30 seconds)")),
-        Line::from(Span::raw("  w - Switch to Words mode (30 words)")),
-        Line::from(""),
-        Line::from(Span::raw("  1 - Go to Test tab")),
-        Line::from(Span::raw("  2 - Go to Stats tab")),
-        Line::from(Span::raw("  3 - Go to Options tab")),
-        Line::from(""),
-        Line::from(Span::raw("  Tab - Next tab")),
-        Line::from(Span::raw("  Esc / q - Quit application")),
-        Line::from(""),
-        Line::from(Span::styled(
-            "  Note: Changing mode will reset the current test.",
-            Style::default().fg(Color::DarkGray),
-        )),
-    ];
+        .style(Style::default().fg(p.dim));
 
-    let paragraph = Paragraph::new(content)
+    let content = match &app.settings_menu.error {
+        Some(error) => Span::styled(error.clone(), Style::default().fg(p.error)),
+        None if app.settings_menu.is_editing() => {
+            Span::styled(msg.settings_editing_hint, Style::default().fg(p.muted))
+        }
+        None => Span::styled(
+            help_view::hint(app, msg.settings_hint, &[Action::Help]),
+            Style::default().fg(p.muted),
+        ),
+    };
+
+    let paragraph = Paragraph::new(Line::from(content))
         .block(block)
-        .alignment(Alignment::Left);
+        .alignment(Alignment::Center);
 
     f.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_group_settings_by_category() {
+        let rows = rows();
+        assert!(matches!(rows[0], Row::Header(Category::Test)));
+        assert!(matches!(rows[1], Row::Item(0, Setting::Mode)));
+        let items = rows.iter().filter(|row| matches!(row, Row::Item(..))).count();
+        assert_eq!(items, Setting::all().len());
+    }
+
+    #[test]
+    fn test_scroll_offset_keeps_selection_visible() {
+        let rows = rows();
+        assert_eq!(scroll_offset(&rows, 0, 5), 0);
+
+        let last = Setting::all().len() - 1;
+        let offset = scroll_offset(&rows, last, 5);
+        assert_eq!(offset + 5, rows.len());
+    }
+}
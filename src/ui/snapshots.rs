@@ -0,0 +1,286 @@
+//! Snapshot tests: each view is drawn into a `TestBackend` and compared with
+//! `src/ui/snapshots/<name>.txt` (the buffer's text and styles). Run with `UPDATE_SNAPSHOTS=1`
+//! to write the expected files after an intended change, then review the diff.
+
+use std::fs;
+use std::path::PathBuf;
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Position;
+use ratatui::style::Modifier;
+use ratatui::Terminal;
+
+use super::{draw, Tab};
+use chrono::NaiveDate;
+
+use crate::app::{App, TextSource};
+use crate::config::Config;
+use crate::i18n::Language;
+use crate::profile::{BestScore, DailyResult, Profile, TestResult};
+use crate::test::{Challenge, DailyChallenge, Script, TestEngine, TestMode, DEFAULT_WORD_LIST};
+use crate::ui::{CaretStyle, ErrorStyle};
+
+const WIDTH: u16 = 100;
+const HEIGHT: u16 = 30;
+
+/// Words of every test in the snapshots
+const WORDS: &str = "the quick brown fox jumps over the lazy dog while seven red birds sing";
+
+/// Get an app with default settings in English, not reading anything from disk
+fn app() -> App {
+    let config = Config {
+        language: Some(Language::English),
+        ..Config::default()
+    };
+    let mut app = App::with_state(config, Vec::new(), Profile::new());
+    app.profile_path = "/home/user/.config/termotype/profile.json".to_string();
+    app.today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+    app.test_engine = Some(play(""));
+    app
+}
+
+/// Play a script on a 12 word test of `WORDS`
+fn play(script: &str) -> TestEngine {
+    let words = WORDS.split_whitespace().map(String::from).collect();
+    Script::parse(script).unwrap().run(TestMode::Words(12), words)
+}
+
+/// Type `text` one key every 250ms
+fn typing(text: &str) -> String {
+    text.chars()
+        .map(|ch| match ch {
+            ' ' => "+250ms <space>".to_string(),
+            _ => format!("+250ms {}", ch),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Draw the app into a test buffer
+fn render(app: &App, caret_visible: bool) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal.draw(|f| draw(f, app, caret_visible)).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// Draw the app into a test buffer, also returning where the terminal cursor was left
+/// The cursor stays at the origin unless the frame placed it.
+fn render_with_cursor(app: &App, caret_visible: bool) -> (Buffer, Position) {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal.draw(|f| draw(f, app, caret_visible)).unwrap();
+    let cursor = terminal.get_cursor_position().unwrap();
+    (terminal.backend().buffer().clone(), cursor)
+}
+
+/// Find the screen position of the first occurrence of `needle` (one cell per character)
+fn find(buffer: &Buffer, needle: &str) -> Position {
+    text(buffer)
+        .lines()
+        .enumerate()
+        .find_map(|(y, line)| {
+            let x = line[..line.find(needle)?].chars().count();
+            Some(Position::new(x as u16, y as u16))
+        })
+        .unwrap_or_else(|| panic!("'{}' is not on screen", needle))
+}
+
+/// Get the text of a buffer, one line per row
+fn text(buffer: &Buffer) -> String {
+    let mut lines: Vec<String> = buffer
+        .content
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>().trim_end().to_string())
+        .collect();
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Compare the drawn app with its stored snapshot
+fn assert_snapshot(name: &str, app: &App) {
+    let actual = format!("{:#?}\n", render(app, true));
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/ui/snapshots").join(format!("{}.txt", name));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("missing snapshot {:?}, run with UPDATE_SNAPSHOTS=1 to create it", path));
+    assert!(
+        actual == expected,
+        "snapshot {} differs (run with UPDATE_SNAPSHOTS=1 to update)\n--- expected\n{}\n--- actual\n{}",
+        name,
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_not_started() {
+    assert_snapshot("test_not_started", &app());
+}
+
+#[test]
+fn test_in_progress() {
+    let mut app = app();
+    app.test_engine = Some(play(&typing("the quikc brown fo")));
+    assert_snapshot("test_in_progress", &app);
+}
+
+#[test]
+fn test_error_underline() {
+    let mut app = app();
+    app.error_style = ErrorStyle::Underline;
+    app.test_engine = Some(play(&typing("the quikc brown fpx")));
+    // The expected characters stay, marked by the underline
+    assert!(text(&render(&app, true)).contains("the quick brown fox"));
+    assert_snapshot("test_error_underline", &app);
+}
+
+#[test]
+fn test_error_glyph() {
+    let mut app = app();
+    app.error_style = ErrorStyle::Glyph;
+    app.test_engine = Some(play(&typing("the quikc brown fpx")));
+    // The typed characters replace the expected ones, in the completed word and in the current one
+    assert!(text(&render(&app, true)).contains("the quikc brown fpx"));
+    assert_snapshot("test_error_glyph", &app);
+}
+
+#[test]
+fn test_finished() {
+    let mut app = app();
+    let mut engine = play(&typing(&format!("{} ", WORDS.replace("lazy", "lasy"))));
+    engine.seed = Some(42);
+    let words: Vec<String> = WORDS.split_whitespace().map(String::from).collect();
+    app.challenge = Some(Challenge::new(engine.mode, DEFAULT_WORD_LIST, &words, 42));
+    app.test_engine = Some(engine);
+    assert_snapshot("test_finished", &app);
+}
+
+#[test]
+fn test_unfocused() {
+    let mut app = app();
+    app.focused = false;
+    assert_snapshot("test_unfocused", &app);
+}
+
+#[test]
+fn test_focus_mode() {
+    let mut app = app();
+    app.focus_mode = true;
+    app.test_engine = Some(play(&typing("the quick")));
+    assert_snapshot("test_focus_mode", &app);
+}
+
+#[test]
+fn test_daily_challenge() {
+    let mut app = app();
+    let yesterday = app.today.pred_opt().unwrap();
+    let result = TestResult::new(TestMode::Words(25), 70.0, 350.0, 98.0, None, Some(DEFAULT_WORD_LIST.into()));
+    app.profile.record_daily(DailyResult::new(yesterday, result));
+    app.text_source = TextSource::Daily(DailyChallenge::for_date(app.today));
+    assert_snapshot("test_daily", &app);
+}
+
+#[test]
+fn test_word_list_warning() {
+    let mut app = app();
+    app.word_list = "lists/typo.json".to_string();
+    app.seed = Some(1);
+    app.init_test();
+    assert_snapshot("test_word_list_warning", &app);
+}
+
+#[test]
+fn test_stats() {
+    let mut app = app();
+    app.current_tab = Tab::Stats;
+    let score = |wpm: f64| BestScore { wpm, cpm: wpm * 5.0, accuracy: 97.5, timestamp: 1_760_000_000 };
+    app.profile.update_score(&TestMode::default_time(), DEFAULT_WORD_LIST, score(72.0));
+    app.profile.update_score(&TestMode::default_words(), DEFAULT_WORD_LIST, score(65.5));
+    // Only the scores of the selected list are shown
+    app.profile.update_score(&TestMode::default_time(), "english", score(99.0));
+    // One more result than the list can show, a minute apart
+    for i in 0..25 {
+        let mode = if i % 2 == 0 { TestMode::default_time() } else { TestMode::Words(50) };
+        let result = TestResult::new(mode, 40.0 + i as f64, 0.0, 95.0, None, None);
+        app.profile.add_result(TestResult { timestamp: 1_760_000_000 + i * 60, ..result });
+    }
+    assert_snapshot("stats", &app);
+
+    app.history_scroll = 10;
+    assert_snapshot("stats_scrolled", &app);
+
+    app.profile = Profile::new();
+    assert_snapshot("stats_empty", &app);
+}
+
+#[test]
+fn test_options() {
+    let mut app = app();
+    app.current_tab = Tab::Options;
+    assert_snapshot("options", &app);
+}
+
+#[test]
+fn test_help_popup() {
+    let mut app = app();
+    app.show_help = true;
+    assert_snapshot("help", &app);
+}
+
+#[test]
+fn test_caret_blink_is_passed_in() {
+    let app = app();
+    let visible = render(&app, true);
+    let hidden = render(&app, false);
+
+    // Only the caret's style changes, and the same state always draws the same frame
+    assert_eq!(text(&visible), text(&hidden));
+    assert_ne!(visible, hidden);
+    assert_eq!(render(&app, true), visible);
+}
+
+#[test]
+fn test_caret_styles() {
+    // The caret is on the 'i' of "quick"
+    let caret_app = |style: CaretStyle| {
+        let mut app = app();
+        app.caret_style = style;
+        app.test_engine = Some(play(&typing("the qu")));
+        app
+    };
+    let (block, _) = render_with_cursor(&caret_app(CaretStyle::Block), true);
+    let quick = find(&block, "quick");
+    let caret = Position::new(quick.x + 2, quick.y);
+    let p = app().palette();
+
+    assert_eq!(block[caret].bg, p.highlight);
+    assert_eq!(block[caret].fg, p.highlight_text);
+
+    let (underline, cursor) = render_with_cursor(&caret_app(CaretStyle::Underline), true);
+    assert!(underline[caret].modifier.contains(Modifier::UNDERLINED));
+    assert_ne!(underline[caret].bg, p.highlight);
+    assert_eq!(cursor, Position::ORIGIN);
+
+    // The bar is the terminal cursor, placed on the column of the caret, so the cell isn't restyled
+    let (bar, cursor) = render_with_cursor(&caret_app(CaretStyle::Bar), true);
+    assert_eq!(cursor, caret);
+    assert_ne!(bar[caret].bg, p.highlight);
+    assert!(!bar[caret].modifier.contains(Modifier::UNDERLINED));
+    let (_, cursor) = render_with_cursor(&caret_app(CaretStyle::Bar), false);
+    assert_eq!(cursor, Position::ORIGIN);
+
+    let (off, cursor) = render_with_cursor(&caret_app(CaretStyle::Off), true);
+    assert_eq!(off, bar);
+    assert_eq!(cursor, Position::ORIGIN);
+
+    // No style shifts the text
+    for buffer in [&underline, &bar, &off] {
+        assert_eq!(text(buffer), text(&block));
+    }
+}
@@ -1,45 +1,115 @@
 use ratatui::{
     layout::{Constraint, Layout, Rect, Alignment},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
 use crate::app::App;
+use crate::i18n::Messages;
+use crate::profile::{BestScore, TestResult};
+use crate::report::{format_timestamp, mode_label};
+use crate::test::TestMode;
+use crate::ui::theme::Palette;
+
+/// Split the stats view into the score cards and the list of recent results
+fn split(area: Rect) -> (Rect, Rect) {
+    let columns = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+    (columns[0], columns[1])
+}
+
+/// Get how many results the list of recent results shows at once
+/// `area` is the whole stats view; the list loses its borders and header row.
+pub fn history_rows(area: Rect) -> usize {
+    list_rows(split(area).1)
+}
+
+/// Get how many results fit in the list drawn in `list`
+fn list_rows(list: Rect) -> usize {
+    list.height.saturating_sub(3) as usize
+}
 
 /// Render the stats view
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let (cards, history) = split(area);
     let chunks = Layout::default()
         .constraints([
             Constraint::Length(12),  // Best 30 seconds
             Constraint::Length(12),  // Best 30 words
             Constraint::Min(3),      // Info
         ])
-        .split(area);
+        .split(cards);
+
+    let msg = app.messages();
+    let p = app.palette();
+    // Scores on different word lists aren't comparable, so the ones of the selected list are shown
+    let best = |mode: TestMode| app.profile.best_score(&mode, &app.word_list);
+    let title = |label: &str| format!("{} ({})", label, app.word_list);
+    render_best_score(f, msg, p, &title(msg.best_30_seconds), best(TestMode::default_time()), chunks[0]);
+    render_best_score(f, msg, p, &title(msg.best_30_words), best(TestMode::default_words()), chunks[1]);
+    render_info(f, msg, p, &app.profile_path, app.profile.daily_streak(app.today), chunks[2]);
+    render_history(f, app, history);
+}
+
+/// Render the list of recent results, newest first, scrolled by `app.history_scroll`
+fn render_history(f: &mut Frame, app: &App, area: Rect) {
+    let msg = app.messages();
+    let p = app.palette();
+    let total = app.profile.history.len();
+    let rows = list_rows(area);
+    let first = app.history_scroll.min(total.saturating_sub(rows));
+    let shown: Vec<&TestResult> = app.profile.history.iter().rev().skip(first).take(rows).collect();
 
-    render_best_score(f, "Best 30 Seconds", &app.profile.best_30_seconds, chunks[0]);
-    render_best_score(f, "Best 30 Words", &app.profile.best_30_words, chunks[1]);
-    render_info(f, chunks[2]);
+    // The position is only worth showing when some results are out of view
+    let title = if total > rows {
+        format!("{} ({}-{}/{})", msg.recent_results, first + 1, first + shown.len(), total)
+    } else {
+        msg.recent_results.to_string()
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(p.text));
+
+    let mut content = vec![Line::from(Span::styled(
+        format!(" {:<17}{:<11}{:>5}  {}", msg.date, msg.mode, "WPM", msg.accuracy),
+        Style::default().fg(p.dim),
+    ))];
+    if shown.is_empty() {
+        content.push(Line::from(Span::styled(format!(" {}", msg.no_score_yet), Style::default().fg(p.muted))));
+    }
+    for result in shown {
+        content.push(Line::from(vec![
+            Span::styled(format!(" {:<17}", format_timestamp(result.timestamp)), Style::default().fg(p.muted)),
+            Span::raw(format!("{:<11}", mode_label(&result.mode))),
+            Span::styled(format!("{:>5.0}", result.wpm), Style::default().fg(p.highlight)),
+            Span::styled(format!("  {:.1}%", result.accuracy), Style::default().fg(p.info)),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(content).block(block), area);
 }
 
 /// Render a best score card
 fn render_best_score(
     f: &mut Frame,
+    msg: &Messages,
+    p: &Palette,
     title: &str,
-    score: &Option<crate::profile::BestScore>,
+    score: Option<&BestScore>,
     area: Rect,
 ) {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(title)
-        .style(Style::default().fg(Color::Cyan));
+        .style(Style::default().fg(p.accent));
 
     let content = if let Some(score) = score {
         // Format timestamp
         let timestamp = chrono::DateTime::from_timestamp(score.timestamp as i64, 0)
             .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-            .unwrap_or_else(|| "Unknown".to_string());
+            .unwrap_or_else(|| msg.unknown.to_string());
 
         vec![
             Line::from(""),
@@ -47,7 +117,7 @@ fn render_best_score(
                 Span::raw("  WPM: "),
                 Span::styled(
                     format!("{:.0}", score.wpm),
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    Style::default().fg(p.highlight).add_modifier(Modifier::BOLD),
                 ),
             ]),
             Line::from(""),
@@ -55,23 +125,23 @@ fn render_best_score(
                 Span::raw("  CPM: "),
                 Span::styled(
                     format!("{:.0}", score.cpm),
-                    Style::default().fg(Color::Green),
+                    Style::default().fg(p.good),
                 ),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::raw("  Accuracy: "),
+                Span::raw(format!("  {}: ", msg.accuracy)),
                 Span::styled(
                     format!("{:.1}%", score.accuracy),
-                    Style::default().fg(Color::Blue),
+                    Style::default().fg(p.info),
                 ),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::raw("  Date: "),
+                Span::raw(format!("  {}: ", msg.date)),
                 Span::styled(
                     timestamp,
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(p.dim),
                 ),
             ]),
         ]
@@ -79,17 +149,17 @@ fn render_best_score(
         vec![
             Line::from(""),
             Line::from(Span::styled(
-                "  No score yet!",
-                Style::default().fg(Color::Gray),
+                format!("  {}", msg.no_score_yet),
+                Style::default().fg(p.muted),
             )),
             Line::from(""),
             Line::from(Span::styled(
-                "  Complete a test to set your",
-                Style::default().fg(Color::DarkGray),
+                format!("  {}", msg.complete_test_line1),
+                Style::default().fg(p.dim),
             )),
             Line::from(Span::styled(
-                "  first record.",
-                Style::default().fg(Color::DarkGray),
+                format!("  {}", msg.complete_test_line2),
+                Style::default().fg(p.dim),
             )),
         ]
     };
@@ -102,33 +172,36 @@ fn render_best_score(
 }
 
 /// Render info section
-fn render_info(f: &mut Frame, area: Rect) {
+fn render_info(f: &mut Frame, msg: &Messages, p: &Palette, profile_path: &str, daily_streak: u32, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Info")
-        .style(Style::default().fg(Color::White));
+        .title(msg.info)
+        .style(Style::default().fg(p.text));
 
     let content = vec![
         Line::from(""),
         Line::from(Span::styled(
-            "  Your best scores are automatically saved!",
-            Style::default().fg(Color::Green),
+            format!("  {}", msg.scores_saved),
+            Style::default().fg(p.good),
         )),
         Line::from(""),
         Line::from(Span::raw(
-            "  Complete tests in 30-second or 30-word modes",
+            format!("  {}", msg.compete_line1),
+        )),
+        Line::from(Span::raw(
+            format!("  {}", msg.compete_line2),
         )),
         Line::from(Span::raw(
-            "  to compete with your personal bests.",
+            format!("  {}: {}", msg.daily_streak, daily_streak),
         )),
         Line::from(""),
         Line::from(Span::styled(
-            "  Profile location:",
-            Style::default().fg(Color::DarkGray),
+            format!("  {}", msg.profile_location),
+            Style::default().fg(p.dim),
         )),
         Line::from(Span::styled(
-            format!("  {}", crate::profile::storage::get_profile_path_display()),
-            Style::default().fg(Color::DarkGray),
+            format!("  {}", profile_path),
+            Style::default().fg(p.dim),
         )),
     ];
 
@@ -1,14 +1,18 @@
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::Span,
     widgets::{Block, Borders, Tabs},
     Frame,
 };
 
+use crate::i18n::Messages;
+use crate::ui::theme::Palette;
+
 /// Available tabs in the application
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Tab {
+    #[default]
     Test,
     Stats,
     Options,
@@ -21,11 +25,11 @@ impl Tab {
     }
 
     /// Get the display name of the tab
-    pub fn name(&self) -> &str {
+    pub fn name(&self, msg: &Messages) -> &'static str {
         match self {
-            Tab::Test => "Test",
-            Tab::Stats => "Statystyki",
-            Tab::Options => "Opcje",
+            Tab::Test => msg.tab_test,
+            Tab::Stats => msg.tab_stats,
+            Tab::Options => msg.tab_options,
         }
     }
 
@@ -48,18 +52,13 @@ impl Tab {
     }
 }
 
-impl Default for Tab {
-    fn default() -> Self {
-        Tab::Test
-    }
-}
-
 /// Render the tab bar at the top of the screen
-pub fn render_tabs(f: &mut Frame, area: Rect, current_tab: Tab) {
+/// `notice` - problem to show on the bottom border (e.g. invalid config file)
+pub fn render_tabs(f: &mut Frame, area: Rect, current_tab: Tab, msg: &Messages, p: &Palette, notice: Option<&str>) {
     let tab_list = Tab::all();
     let titles: Vec<Span> = tab_list
         .iter()
-        .map(|t| Span::raw(t.name()))
+        .map(|t| Span::raw(t.name(msg)))
         .collect();
 
     let current_index = match current_tab {
@@ -68,24 +67,51 @@ pub fn render_tabs(f: &mut Frame, area: Rect, current_tab: Tab) {
         Tab::Options => 2,
     };
 
+    let mut block = Block::default()
+        .title(msg.app_title)
+        .borders(Borders::ALL)
+        .style(Style::default().fg(p.accent));
+    if let Some(notice) = notice {
+        block = block.title_bottom(Span::styled(format!(" {} ", notice), Style::default().fg(p.error)));
+    }
+
     let tabs = Tabs::new(titles)
-        .block(
-            Block::default()
-                .title("TermoType - Typing Speed Test")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Cyan)),
-        )
+        .block(block)
         .select(current_index)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(p.text))
         .highlight_style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(p.highlight)
                 .add_modifier(Modifier::BOLD),
         );
 
     f.render_widget(tabs, area);
 }
 
+/// Find the tab whose title is at the given screen position
+/// Mirrors the `Tabs` layout: one column of padding around each title and a one-column divider
+pub fn tab_at(area: Rect, column: u16, row: u16, msg: &Messages) -> Option<Tab> {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    if row != inner.y || column < inner.x {
+        return None;
+    }
+
+    let mut x = inner.x;
+    for tab in Tab::all() {
+        let width = Span::raw(tab.name(msg)).width() as u16 + 2;
+        if column < x {
+            // On a divider
+            return None;
+        }
+        if column < x + width {
+            return Some(tab);
+        }
+        x += width + 1;
+    }
+
+    None
+}
+
 /// Split the screen into tab bar and content area
 pub fn split_screen(area: Rect) -> (Rect, Rect) {
     let chunks = Layout::default()
@@ -94,3 +120,21 @@ pub fn split_screen(area: Rect) -> (Rect, Rect) {
 
     (chunks[0], chunks[1])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::ENGLISH;
+
+    #[test]
+    fn test_tab_at() {
+        let area = Rect::new(0, 0, 80, 3);
+        // Titles start after the border and one column of padding: " Test │ Stats │ Options "
+        assert_eq!(tab_at(area, 2, 1, &ENGLISH), Some(Tab::Test));
+        assert_eq!(tab_at(area, 9, 1, &ENGLISH), Some(Tab::Stats));
+        assert_eq!(tab_at(area, 17, 1, &ENGLISH), Some(Tab::Options));
+        assert_eq!(tab_at(area, 7, 1, &ENGLISH), None);
+        assert_eq!(tab_at(area, 2, 0, &ENGLISH), None);
+        assert_eq!(tab_at(area, 60, 1, &ENGLISH), None);
+    }
+}
@@ -1,117 +1,324 @@
+use std::rc::Rc;
+
 use ratatui::{
-    layout::{Constraint, Layout, Rect, Alignment},
+    layout::{Constraint, Layout, Position, Rect, Alignment},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{App, TextSource};
+use crate::i18n::Messages;
+use crate::keymap::Action;
+use crate::ui::help_view;
+use crate::ui::theme::Palette;
+use crate::test::{CharState, TestState, WordState};
+
+/// How typing errors are marked in the words display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorStyle {
+    /// Red text only
+    #[default]
+    Color,
+    /// Red, underlined text
+    Underline,
+    /// Red, struck-through text
+    Strikethrough,
+    /// The typed character in place of the expected one, in inverse video
+    Glyph,
+}
+
+impl ErrorStyle {
+    /// Get the display name of the error style
+    pub fn name(&self, msg: &Messages) -> &'static str {
+        match self {
+            ErrorStyle::Color => msg.error_style_color,
+            ErrorStyle::Underline => msg.error_style_underline,
+            ErrorStyle::Strikethrough => msg.error_style_strikethrough,
+            ErrorStyle::Glyph => msg.error_style_glyph,
+        }
+    }
 
-use crate::app::App;
-use crate::test::{CharState, TestState};
+    /// Get the next error style (cycling)
+    pub fn next(&self) -> ErrorStyle {
+        match self {
+            ErrorStyle::Color => ErrorStyle::Underline,
+            ErrorStyle::Underline => ErrorStyle::Strikethrough,
+            ErrorStyle::Strikethrough => ErrorStyle::Glyph,
+            ErrorStyle::Glyph => ErrorStyle::Color,
+        }
+    }
+
+    /// Get the previous error style (cycling)
+    pub fn prev(&self) -> ErrorStyle {
+        match self {
+            ErrorStyle::Color => ErrorStyle::Glyph,
+            ErrorStyle::Underline => ErrorStyle::Color,
+            ErrorStyle::Strikethrough => ErrorStyle::Underline,
+            ErrorStyle::Glyph => ErrorStyle::Strikethrough,
+        }
+    }
+
+    /// Style used for an error, so it stays visible without relying on colour alone
+    fn style(&self, p: &Palette) -> Style {
+        let style = Style::default().fg(p.error);
+        match self {
+            ErrorStyle::Color => style,
+            ErrorStyle::Underline => style.add_modifier(Modifier::UNDERLINED),
+            ErrorStyle::Strikethrough => style.add_modifier(Modifier::CROSSED_OUT),
+            ErrorStyle::Glyph => style.add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Check if mistakes are drawn as the typed character, which needs the word reviewed per character
+    fn shows_typed_chars(&self) -> bool {
+        *self == ErrorStyle::Glyph
+    }
+}
+
+/// Caret appearance in the words display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaretStyle {
+    /// Highlighted cell under the caret
+    #[default]
+    Block,
+    /// Underlined character under the caret
+    Underline,
+    /// Thin bar drawn with the terminal cursor
+    Bar,
+    /// No caret
+    Off,
+}
+
+impl CaretStyle {
+    /// Get the display name of the caret style
+    pub fn name(&self, msg: &Messages) -> &'static str {
+        match self {
+            CaretStyle::Block => msg.caret_block,
+            CaretStyle::Underline => msg.caret_underline,
+            CaretStyle::Bar => msg.caret_bar,
+            CaretStyle::Off => msg.off,
+        }
+    }
+
+    /// Get the next caret style (cycling)
+    pub fn next(&self) -> CaretStyle {
+        match self {
+            CaretStyle::Block => CaretStyle::Underline,
+            CaretStyle::Underline => CaretStyle::Bar,
+            CaretStyle::Bar => CaretStyle::Off,
+            CaretStyle::Off => CaretStyle::Block,
+        }
+    }
+
+    /// Get the previous caret style (cycling)
+    pub fn prev(&self) -> CaretStyle {
+        match self {
+            CaretStyle::Block => CaretStyle::Off,
+            CaretStyle::Underline => CaretStyle::Block,
+            CaretStyle::Bar => CaretStyle::Underline,
+            CaretStyle::Off => CaretStyle::Bar,
+        }
+    }
+}
 
 /// Render the test view
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
+/// `caret_visible` is the blink state for this frame, so drawing doesn't depend on the clock.
+pub fn render(f: &mut Frame, app: &App, caret_visible: bool, area: Rect) {
+    let msg = app.messages();
     let Some(engine) = &app.test_engine else {
-        render_no_test(f, area);
+        render_no_test(f, msg, app.palette(), area);
         return;
     };
 
-    // Split into stats area and content area
-    let chunks = Layout::default()
-        .constraints([
-            Constraint::Length(3),  // Stats bar
-            Constraint::Min(10),    // Words display (3 lines centered)
-            Constraint::Length(3),  // Help text
-        ])
-        .split(area);
+    let chunks = layout(area);
 
     // Render stats bar
     render_stats_bar(f, app, engine, chunks[0]);
 
-    // Render 3-line words display
-    render_words_three_lines(f, engine, chunks[1]);
+    // Render 3-line words display (or a hint to focus it again)
+    if app.focused {
+        render_words_three_lines(f, app, engine, caret_visible, chunks[1]);
+    } else {
+        render_unfocused(f, msg, app.palette(), chunks[1]);
+    }
 
     // Render help/instructions
-    render_help(f, engine, chunks[2]);
+    render_help(f, app, engine, chunks[2]);
+}
+
+/// Render only the words (and optionally the time) for focus mode
+pub fn render_focused(f: &mut Frame, app: &App, caret_visible: bool, area: Rect) {
+    let Some(engine) = &app.test_engine else {
+        return;
+    };
+
+    let p = app.palette();
+    let words_area = if app.focus_timer {
+        let chunks = Layout::default()
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        let timer = Paragraph::new(time_display(app.messages(), engine))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(p.dim));
+        f.render_widget(timer, chunks[0]);
+        chunks[1]
+    } else {
+        area
+    };
+
+    if app.focused {
+        render_words_three_lines(f, app, engine, caret_visible, words_area);
+    } else {
+        render_unfocused(f, app.messages(), app.palette(), words_area);
+    }
+}
+
+/// Split into stats area and content area
+fn layout(area: Rect) -> Rc<[Rect]> {
+    Layout::default()
+        .constraints([
+            Constraint::Length(3),  // Stats bar
+            Constraint::Min(10),    // Words display (3 lines centered)
+            Constraint::Length(3),  // Help text
+        ])
+        .split(area)
+}
+
+/// Check if a screen position is inside the words display
+pub fn words_area_contains(area: Rect, column: u16, row: u16) -> bool {
+    layout(area)[1].contains(Position::new(column, row))
 }
 
 /// Render when test engine is not initialized
-fn render_no_test(f: &mut Frame, area: Rect) {
+fn render_no_test(f: &mut Frame, msg: &Messages, p: &Palette, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Test")
-        .style(Style::default().fg(Color::White));
+        .title(msg.tab_test)
+        .style(Style::default().fg(p.text));
 
-    let paragraph = Paragraph::new("Loading test...")
+    let paragraph = Paragraph::new(msg.loading_test)
         .block(block)
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(p.text));
 
     f.render_widget(paragraph, area);
 }
 
-/// Render the stats bar with metrics
-fn render_stats_bar(f: &mut Frame, _app: &App, engine: &crate::test::TestEngine, area: Rect) {
-    let metrics = engine.get_metrics();
-
-    // Calculate time remaining or elapsed
-    let time_display = match engine.mode {
+/// Format the time remaining (time tests) or elapsed (word tests)
+fn time_display(msg: &Messages, engine: &crate::test::TestEngine) -> String {
+    match engine.mode {
         crate::test::TestMode::Time(seconds) => {
             let remaining = (seconds as f64 - engine.elapsed_seconds()).max(0.0);
-            format!("Time: {:.1}s", remaining)
+            format!("{}: {:.1}s", msg.time, remaining)
         }
         crate::test::TestMode::Words(_) => {
-            format!("Time: {:.1}s", engine.elapsed_seconds())
+            format!("{}: {:.1}s", msg.time, engine.elapsed_seconds())
         }
-    };
+    }
+}
+
+/// Render the stats bar with metrics
+fn render_stats_bar(f: &mut Frame, app: &App, engine: &crate::test::TestEngine, area: Rect) {
+    let msg = app.messages();
+    let p = app.palette();
+    let metrics = engine.get_metrics();
+
+    let time_display = time_display(msg, engine);
 
     // Calculate progress
     let progress_display = match engine.mode {
         crate::test::TestMode::Time(_) => {
-            format!("Words: {}", engine.current_word_index)
+            format!("{}: {}", msg.words, engine.current_word_index)
         }
         crate::test::TestMode::Words(count) => {
-            format!("Progress: {}/{}", engine.current_word_index, count)
+            format!("{}: {}/{}", msg.progress, engine.current_word_index, count)
         }
     };
 
-    let stats_text = format!(
-        " {} | {} | WPM: {:.0} | CPM: {:.0} | Accuracy: {:.1}% ",
+    // Live speed while typing, the cumulative result once finished
+    let wpm = match engine.state {
+        TestState::InProgress => engine.live_wpm(),
+        _ => metrics.wpm,
+    };
+
+    let mut stats_text = format!(
+        " {} | {} | WPM: {:.0} | CPM: {:.0} | {}: {:.1}% ",
         time_display,
         progress_display,
-        metrics.wpm,
+        wpm,
         metrics.cpm,
+        msg.accuracy,
         metrics.accuracy
     );
+    // The seed is shown with the result, so the same test can be typed again
+    if engine.state == TestState::Finished
+        && let Some(seed) = engine.seed
+    {
+        stats_text.push_str(&format!("| {}: {} ", msg.seed, seed));
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Cyan));
+        .style(Style::default().fg(p.accent));
 
     let paragraph = Paragraph::new(stats_text)
         .block(block)
-        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        .style(Style::default().fg(p.highlight).add_modifier(Modifier::BOLD));
 
     f.render_widget(paragraph, area);
 }
 
+/// Get the title of the words display: the challenge code of a result, or the daily challenge when it is running
+fn words_title(app: &App) -> String {
+    let msg = app.messages();
+
+    // The code to share a test comes with its result (and stays on screen while a shared one is typed)
+    let finished = app.test_engine.as_ref().is_some_and(|engine| engine.state == TestState::Finished);
+    if (finished || matches!(app.text_source, TextSource::Challenge(..)))
+        && let Some(challenge) = &app.challenge
+    {
+        return format!("{}: {}", msg.challenge_code, challenge.code());
+    }
+
+    match &app.text_source {
+        TextSource::Daily(challenge) => format!(
+            "{} {} | {}: {}",
+            msg.daily_challenge,
+            challenge.date,
+            msg.daily_streak,
+            app.profile.daily_streak(challenge.date)
+        ),
+        _ => msg.type_the_words.to_string(),
+    }
+}
+
 /// Render 3 lines of words centered on screen
-fn render_words_three_lines(f: &mut Frame, engine: &crate::test::TestEngine, area: Rect) {
+fn render_words_three_lines(
+    f: &mut Frame,
+    app: &App,
+    engine: &crate::test::TestEngine,
+    show_caret: bool,
+    area: Rect,
+) {
+    let p = app.palette();
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Type the words")
-        .style(Style::default().fg(Color::White));
+        .title(words_title(app))
+        .style(Style::default().fg(p.text));
+    let inner = block.inner(area);
 
     // Get current line and the next 2 lines
     let current_line_idx = engine.current_line_index;
     let lines_to_display = 3;
 
-    // Calculate cursor blink state (530ms on, 530ms off) for smooth blinking
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_millis();
-    let show_cursor = (now % 1060) < 530;
+    // The caret only restyles a cell (or moves the terminal cursor), so it never shifts the text
+    let caret = Caret { style: app.caret_style, visible: show_caret, palette: p };
+    // Column of the caret within the current line, for the terminal cursor
+    let mut caret_column: Option<u16> = None;
 
     let mut display_lines: Vec<Line> = Vec::new();
 
@@ -139,10 +346,13 @@ fn render_words_three_lines(f: &mut Frame, engine: &crate::test::TestEngine, are
         // Determine if this is the current line
         let is_current_line = line_offset == 0;
 
+        // Set when the caret sits past the end of the current word, on the following space
+        let mut caret_on_next_space = false;
+
         for (word_idx_in_line, word) in line_words.iter().enumerate() {
             // Add space before word (except first)
             if !line_spans.is_empty() {
-                line_spans.push(Span::raw(" "));
+                push_space(&mut line_spans, &mut caret_on_next_space, caret, &mut caret_column);
             }
 
             // Calculate global word index
@@ -158,54 +368,78 @@ fn render_words_three_lines(f: &mut Frame, engine: &crate::test::TestEngine, are
                 // Current word being typed - render with live feedback
                 if let Some(word_state) = &engine.current_word_state {
                     for (char_idx, ch) in word.chars().enumerate() {
-                        // Add cursor BEFORE the current character
-                        if show_cursor && char_idx == word_state.cursor_pos {
-                            line_spans.push(Span::styled("|", Style::default().fg(Color::Yellow)));
-                        }
-
-                        let style = if char_idx < word_state.char_states.len() {
-                            match word_state.char_states[char_idx] {
-                                CharState::Correct => Style::default().fg(Color::White),
-                                CharState::Incorrect => Style::default().fg(Color::LightRed),
-                                CharState::Untyped => Style::default().fg(Color::Gray),
-                            }
+                        let span = char_span(
+                            ch,
+                            word_state,
+                            char_idx,
+                            p.muted,
+                            app.error_style,
+                            app.show_typed_chars,
+                            p,
+                        );
+
+                        if char_idx == word_state.cursor_pos {
+                            caret_column = Some(spans_width(&line_spans));
+                            line_spans.push(caret.apply(span));
                         } else {
-                            Style::default().fg(Color::Gray)
-                        };
-
-                        line_spans.push(Span::styled(ch.to_string(), style));
+                            line_spans.push(span);
+                        }
                     }
 
-                    // If cursor is at the end of the word, add it after
-                    if show_cursor && word_state.cursor_pos >= word.chars().count() {
-                        line_spans.push(Span::styled("|", Style::default().fg(Color::Yellow)));
-                    }
+                    caret_on_next_space = word_state.is_complete();
+                }
+            } else if let Some(word_state) = engine
+                .completed_word(global_word_idx)
+                .filter(|_| is_typed && (app.show_typed_chars || app.error_style.shows_typed_chars()))
+            {
+                // Already typed word - review what was actually typed, character by character
+                for (char_idx, ch) in word.chars().enumerate() {
+                    let span = char_span(ch, word_state, char_idx, p.text, app.error_style, app.show_typed_chars, p);
+                    line_spans.push(span);
                 }
             } else if is_typed {
-                // Already typed word - show in white (or red if had errors)
+                // Already typed word - show in white (or marked as an error)
                 let had_errors = engine.word_had_errors.get(global_word_idx).copied().unwrap_or(false);
-                let color = if had_errors {
-                    Color::LightRed
+                let style = if had_errors {
+                    app.error_style.style(p)
                 } else {
-                    Color::White
+                    Style::default().fg(p.text)
                 };
-                line_spans.push(Span::styled(word.clone(), Style::default().fg(color)));
+                line_spans.push(Span::styled(word.clone(), style));
             } else {
                 // Future word - show in gray (darker for line 2)
                 let color = if line_offset == 1 {
-                    Color::DarkGray
+                    p.dim
                 } else if line_offset == 2 {
-                    Color::Rgb(60, 60, 60) // Even darker gray
+                    p.faint // Even darker gray
                 } else {
-                    Color::Gray
+                    p.muted
                 };
                 line_spans.push(Span::styled(word.clone(), Style::default().fg(color)));
             }
         }
 
+        // Trailing space keeps room for the caret after the last word of every line
+        push_space(&mut line_spans, &mut caret_on_next_space, caret, &mut caret_column);
+
         display_lines.push(Line::from(line_spans));
     }
 
+    // Place the real terminal cursor for the bar caret (same offset Paragraph uses to center)
+    if caret.style == CaretStyle::Bar
+        && caret.visible
+        && let Some(column) = caret_column
+        && let Some(current_line) = display_lines.get(padding_top as usize)
+    {
+        let line_width = current_line.width() as u16;
+        let offset = (inner.width / 2).saturating_sub(line_width / 2);
+        let x = inner.x + offset + column;
+        let y = inner.y + padding_top;
+        if x < inner.right() && y < inner.bottom() {
+            f.set_cursor_position((x, y));
+        }
+    }
+
     let paragraph = Paragraph::new(display_lines)
         .block(block)
         .alignment(Alignment::Center);
@@ -213,22 +447,128 @@ fn render_words_three_lines(f: &mut Frame, engine: &crate::test::TestEngine, are
     f.render_widget(paragraph, area);
 }
 
+/// Render the words area while the test doesn't have focus
+fn render_unfocused(f: &mut Frame, msg: &Messages, p: &Palette, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(msg.type_the_words)
+        .style(Style::default().fg(p.dim));
+
+    let padding_top = area.height.saturating_sub(2) / 2;
+    let mut content: Vec<Line> = (0..padding_top).map(|_| Line::from("")).collect();
+    content.push(Line::from(Span::styled(
+        msg.click_to_focus,
+        Style::default().fg(p.highlight).add_modifier(Modifier::BOLD),
+    )));
+
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    f.render_widget(paragraph, area);
+}
+
+/// Caret appearance for the current frame
+#[derive(Debug, Clone, Copy)]
+struct Caret {
+    style: CaretStyle,
+    visible: bool,
+    palette: &'static Palette,
+}
+
+impl Caret {
+    /// Apply the caret to the span of the character under it
+    fn apply(self, span: Span<'static>) -> Span<'static> {
+        if !self.visible {
+            return span;
+        }
+
+        let p = self.palette;
+        match self.style {
+            CaretStyle::Block => span.style(Style::default().fg(p.highlight_text).bg(p.highlight)),
+            CaretStyle::Underline => {
+                let style = span.style.add_modifier(Modifier::UNDERLINED).underline_color(p.highlight);
+                span.style(style)
+            }
+            // The bar is drawn with the terminal cursor instead
+            CaretStyle::Bar | CaretStyle::Off => span,
+        }
+    }
+}
+
+/// Push a separating space, placing the caret on it if it is pending
+fn push_space(
+    line_spans: &mut Vec<Span<'static>>,
+    caret_pending: &mut bool,
+    caret: Caret,
+    caret_column: &mut Option<u16>,
+) {
+    if *caret_pending {
+        *caret_pending = false;
+        *caret_column = Some(spans_width(line_spans));
+        line_spans.push(caret.apply(Span::raw(" ")));
+    } else {
+        line_spans.push(Span::raw(" "));
+    }
+}
+
+/// Total display width of a list of spans
+fn spans_width(spans: &[Span]) -> u16 {
+    spans.iter().map(|s| s.width() as u16).sum()
+}
+
+/// Build the span for a single character of a word that is being (or was) typed
+/// `untyped_color` - colour for positions the user hasn't typed
+/// `show_typed_chars` - show the mistyped character instead of the expected one
+/// (`ErrorStyle::Glyph` always does)
+fn char_span(
+    expected: char,
+    word_state: &WordState,
+    char_idx: usize,
+    untyped_color: Color,
+    error_style: ErrorStyle,
+    show_typed_chars: bool,
+    p: &Palette,
+) -> Span<'static> {
+    let state = word_state.char_states.get(char_idx).copied().unwrap_or(CharState::Untyped);
+    match state {
+        CharState::Correct => Span::styled(expected.to_string(), Style::default().fg(p.text)),
+        CharState::Incorrect => match word_state.typed_char(char_idx) {
+            Some(typed) if show_typed_chars || error_style.shows_typed_chars() => {
+                Span::styled(typed.to_string(), error_style.style(p))
+            }
+            _ => Span::styled(expected.to_string(), error_style.style(p)),
+        },
+        CharState::Untyped => Span::styled(expected.to_string(), Style::default().fg(untyped_color)),
+    }
+}
+
 /// Render help text
-fn render_help(f: &mut Frame, engine: &crate::test::TestEngine, area: Rect) {
+fn render_help(f: &mut Frame, app: &App, engine: &crate::test::TestEngine, area: Rect) {
+    let msg = app.messages();
+    let p = app.palette();
+    let actions = [Action::Restart, Action::NextTab, Action::Help, Action::Quit];
     let help_text = match engine.state {
-        TestState::NotStarted => "Start typing to begin | Enter: Reset | Tab: Change tab | Esc: Quit",
-        TestState::InProgress => "Type the words | Space: Next word | Enter: Reset | Tab: Change tab | Esc: Quit",
-        TestState::Finished => "Test finished! | Enter: Reset | Tab: View stats | Esc: Quit",
+        TestState::NotStarted => help_view::hint(app, msg.start_typing, &actions),
+        TestState::InProgress => {
+            let prefix = format!("{} | Space: {}", msg.type_the_words_hint, msg.key_next_word);
+            help_view::hint(app, &prefix, &actions)
+        }
+        TestState::Finished if engine.is_retry() => {
+            let prefix = format!("{} ({})", msg.test_finished, msg.retry_note);
+            help_view::hint(app, &prefix, &actions)
+        }
+        TestState::Finished => help_view::hint(app, msg.test_finished, &actions),
     };
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(p.dim));
 
     let paragraph = Paragraph::new(help_text)
         .block(block)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Gray));
+        .style(Style::default().fg(p.muted));
 
     f.render_widget(paragraph, area);
 }
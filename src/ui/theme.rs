@@ -0,0 +1,139 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Messages;
+
+/// Colors used across the UI, by role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Borders and headings
+    pub accent: Color,
+    /// Regular and correctly typed text
+    pub text: Color,
+    /// Hints and secondary text
+    pub muted: Color,
+    /// Inactive borders, untyped words and dates
+    pub dim: Color,
+    /// Words two lines ahead
+    pub faint: Color,
+    /// Selection, live stats and the caret
+    pub highlight: Color,
+    /// Text drawn on top of `highlight` (block caret)
+    pub highlight_text: Color,
+    /// Typing errors and problems
+    pub error: Color,
+    /// Positive values (CPM, saved scores)
+    pub good: Color,
+    /// Other values (accuracy)
+    pub info: Color,
+}
+
+/// Original colors
+static DEFAULT: Palette = Palette {
+    accent: Color::Cyan,
+    text: Color::White,
+    muted: Color::Gray,
+    dim: Color::DarkGray,
+    faint: Color::Rgb(60, 60, 60),
+    highlight: Color::Yellow,
+    highlight_text: Color::Black,
+    error: Color::LightRed,
+    good: Color::Green,
+    info: Color::Blue,
+};
+
+/// Grays only, for terminals with few colors or to cut distraction
+/// Errors still stand out through the error indication style.
+static MONO: Palette = Palette {
+    accent: Color::Gray,
+    text: Color::White,
+    muted: Color::Gray,
+    dim: Color::DarkGray,
+    faint: Color::DarkGray,
+    highlight: Color::White,
+    highlight_text: Color::Black,
+    error: Color::White,
+    good: Color::White,
+    info: Color::White,
+};
+
+/// Solarized dark
+static SOLARIZED: Palette = Palette {
+    accent: Color::Rgb(38, 139, 210),
+    text: Color::Rgb(238, 232, 213),
+    muted: Color::Rgb(147, 161, 161),
+    dim: Color::Rgb(88, 110, 117),
+    faint: Color::Rgb(7, 54, 66),
+    highlight: Color::Rgb(181, 137, 0),
+    highlight_text: Color::Rgb(0, 43, 54),
+    error: Color::Rgb(220, 50, 47),
+    good: Color::Rgb(133, 153, 0),
+    info: Color::Rgb(42, 161, 152),
+};
+
+/// Color theme of the UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    #[default]
+    Default,
+    Mono,
+    Solarized,
+}
+
+impl Theme {
+    /// Get all themes
+    pub fn all() -> Vec<Theme> {
+        vec![Theme::Default, Theme::Mono, Theme::Solarized]
+    }
+
+    /// Get the name used for the theme in config.json and on the command line
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            Theme::Default => "default",
+            Theme::Mono => "mono",
+            Theme::Solarized => "solarized",
+        }
+    }
+
+    /// Find a theme by its config name
+    pub fn from_config_name(name: &str) -> Option<Theme> {
+        Theme::all().into_iter().find(|theme| theme.config_name() == name)
+    }
+
+    /// Get the display name of the theme
+    pub fn name(&self, msg: &Messages) -> &'static str {
+        match self {
+            Theme::Default => msg.theme_default,
+            Theme::Mono => "Mono",
+            Theme::Solarized => "Solarized",
+        }
+    }
+
+    /// Get the next theme (cycling)
+    pub fn next(&self) -> Theme {
+        match self {
+            Theme::Default => Theme::Mono,
+            Theme::Mono => Theme::Solarized,
+            Theme::Solarized => Theme::Default,
+        }
+    }
+
+    /// Get the previous theme (cycling)
+    pub fn prev(&self) -> Theme {
+        match self {
+            Theme::Default => Theme::Solarized,
+            Theme::Mono => Theme::Default,
+            Theme::Solarized => Theme::Mono,
+        }
+    }
+
+    /// Get the colors of the theme
+    pub fn palette(&self) -> &'static Palette {
+        match self {
+            Theme::Default => &DEFAULT,
+            Theme::Mono => &MONO,
+            Theme::Solarized => &SOLARIZED,
+        }
+    }
+}